@@ -79,7 +79,7 @@ impl From<Errno> for BackendError {
     fn from(err: Errno) -> Self {
         match err {
             Errno::EPERM | Errno::EACCES => Self::NotAllowed,
-            Errno::ENOENT => Self::NotFound,
+            Errno::ENOENT | Errno::ESRCH => Self::NotFound,
             k => Self::NixError(k),
         }
     }
@@ -9,52 +9,71 @@ use crate::backend::{
     BackendResult,
 };
 
-type ProcDataSource<T> = fn() -> ProcResult<T>;
-
-fn pds_from_current<T: Current + Sized>() -> ProcDataSource<T> {
-    T::current
-}
-
-fn pds_from_current_si<T: CurrentSI + Sized>() -> ProcDataSource<T> {
-    T::current
-}
+type ProcDataSource<T> = Box<dyn Fn() -> ProcResult<T>>;
 
 /// Wrapper to fetch updated data from a `/proc` file.
 pub(super) struct ProcFSWrapper<T> {
     fetch: ProcDataSource<T>,
+    keep_baseline: Option<fn(&T) -> T>,
+    merge_baseline: Option<fn(&mut T, &T)>,
     state: RefCell<ProcFSData<T>>,
 }
 
 pub(super) struct ProcFSData<T> {
     pub current: Option<T>,
     pub previous: Option<T>,
+    /// The first observation (plus any merged later ones), if the wrapper keeps
+    /// a baseline.
+    pub baseline: Option<T>,
     pub window: RefreshRecord,
 }
 
 impl<T> ProcFSWrapper<T> {
-    pub(super) fn new(fetch: ProcDataSource<T>, tick: &Tick) -> Self {
+    pub(super) fn new<F: Fn() -> ProcResult<T> + 'static>(fetch: F, tick: &Tick) -> Self {
         ProcFSWrapper {
-            fetch,
+            fetch: Box::new(fetch),
+            keep_baseline: None,
+            merge_baseline: None,
             state: RefCell::new(ProcFSData {
                 current: None,
                 previous: None,
+                baseline: None,
                 window: RefreshRecord::with_tick(tick.clone()),
             }),
         }
     }
 }
 
-impl<T: Current> ProcFSWrapper<T> {
+impl<T: Clone> ProcFSWrapper<T> {
+    /// Keep a copy of the first observation, for cumulative counts since startup.
+    pub(super) fn with_baseline(self) -> Self {
+        ProcFSWrapper {
+            keep_baseline: Some(T::clone),
+            ..self
+        }
+    }
+
+    /// Keep a baseline as for [with_baseline](Self::with_baseline), and merge each
+    /// later observation into it (e.g. to add devices that appear after startup).
+    pub(super) fn with_merged_baseline(self, merge: fn(&mut T, &T)) -> Self {
+        ProcFSWrapper {
+            merge_baseline: Some(merge),
+            ..self.with_baseline()
+        }
+    }
+}
+
+impl<T: Current + 'static> ProcFSWrapper<T> {
     /// Create a new data (fetches from a [Current] instance).
     pub(super) fn for_current(tick: &Tick) -> Self {
-        ProcFSWrapper::new(pds_from_current::<T>(), tick)
+        ProcFSWrapper::new(<T as Current>::current, tick)
     }
 }
 
-impl<T: CurrentSI> ProcFSWrapper<T> {
+impl<T: CurrentSI + 'static> ProcFSWrapper<T> {
     /// Create a new data (fetches from a [CurrentSI] instance).
     pub(super) fn for_curent_si(tick: &Tick) -> Self {
-        ProcFSWrapper::new(pds_from_current_si::<T>(), tick)
+        ProcFSWrapper::new(<T as CurrentSI>::current, tick)
     }
 }
 
@@ -63,9 +82,18 @@ impl<T> ProcFSWrapper<T> {
     pub(super) fn data<'a>(&'a self) -> BackendResult<Ref<'a, ProcFSData<T>>> {
         let mut state = self.state.borrow_mut();
         if !state.window.is_current() {
+            // data that was not read at the last refresh (e.g. thread records while
+            // listing processes) would span several refreshes, so is dropped
+            let consecutive = state.window.is_previous();
             let cur = (self.fetch)()?;
             state.window.update();
-            state.previous = state.current.replace(cur);
+            match (&mut state.baseline, self.keep_baseline, self.merge_baseline) {
+                (Some(base), _, Some(merge)) => merge(base, &cur),
+                (None, Some(copy), _) => state.baseline = Some(copy(&cur)),
+                _ => (),
+            }
+            let previous = state.current.replace(cur);
+            state.previous = previous.filter(|_| consecutive);
         }
         drop(state);
 
@@ -0,0 +1,169 @@
+//! Linux open file descriptor listing.
+use std::collections::HashMap;
+use std::fs;
+use std::net::SocketAddr;
+
+use procfs::net::{TcpNetEntry, TcpState, UdpNetEntry, UnixState};
+use procfs::process::{FDTarget, Process as LinuxProcess};
+
+use crate::backend::BackendResult;
+use crate::model::files::{FileAccess, OpenFile, OpenFileKind, SocketInfo};
+
+/// Status flags to report from `fdinfo`, with their names.
+const FD_FLAGS: &[(libc::c_int, &str)] = &[
+    (libc::O_APPEND, "append"),
+    (libc::O_NONBLOCK, "nonblock"),
+    (libc::O_SYNC, "sync"),
+    (libc::O_DIRECT, "direct"),
+    (libc::O_CLOEXEC, "cloexec"),
+    (libc::O_PATH, "path"),
+];
+
+/// Read the open file descriptors of a process.
+pub(super) fn read_open_files(pid: i32) -> BackendResult<Vec<OpenFile>> {
+    let proc = LinuxProcess::new(pid)?;
+    // descriptors can be closed while we are listing them, so skip failures
+    let fds: Vec<_> = proc.fd()?.filter_map(|fd| fd.ok()).collect();
+
+    let sockets = if fds
+        .iter()
+        .any(|fd| matches!(fd.target, FDTarget::Socket(_)))
+    {
+        socket_table(&proc)
+    } else {
+        HashMap::new()
+    };
+
+    let mut files = Vec::with_capacity(fds.len());
+    for fd in fds {
+        let (kind, name) = match fd.target {
+            FDTarget::Path(path) => (OpenFileKind::Path, path.display().to_string()),
+            FDTarget::Pipe(ino) => (OpenFileKind::Pipe, format!("pipe:[{}]", ino)),
+            FDTarget::Socket(ino) => (
+                OpenFileKind::Socket(sockets.get(&ino).cloned()),
+                format!("socket:[{}]", ino),
+            ),
+            FDTarget::Net(ino) => (OpenFileKind::Other, format!("net:[{}]", ino)),
+            FDTarget::AnonInode(name) => (OpenFileKind::AnonInode, name),
+            FDTarget::MemFD(name) => (OpenFileKind::MemFD, name),
+            FDTarget::Other(name, ino) => (OpenFileKind::Other, format!("{}:[{}]", name, ino)),
+        };
+        let (pos, flags) = read_fdinfo(pid, fd.fd).unwrap_or_default();
+        files.push(OpenFile {
+            fd: fd.fd,
+            kind,
+            name,
+            pos,
+            access: flags.map(|f| match f & libc::O_ACCMODE {
+                libc::O_WRONLY => FileAccess::Write,
+                libc::O_RDWR => FileAccess::ReadWrite,
+                _ => FileAccess::Read,
+            }),
+            flags: flags
+                .map(|f| {
+                    FD_FLAGS
+                        .iter()
+                        .filter(|(flag, _)| f & flag == *flag)
+                        .map(|(_, name)| *name)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        });
+    }
+    files.sort_by_key(|f| f.fd);
+    Ok(files)
+}
+
+/// Read the position and flags of a file descriptor.
+fn read_fdinfo(pid: i32, fd: i32) -> Option<(Option<u64>, Option<libc::c_int>)> {
+    let text = fs::read_to_string(format!("/proc/{}/fdinfo/{}", pid, fd)).ok()?;
+    let mut pos = None;
+    let mut flags = None;
+    for line in text.lines() {
+        if let Some((key, val)) = line.split_once(':') {
+            match key {
+                "pos" => pos = val.trim().parse().ok(),
+                "flags" => flags = libc::c_int::from_str_radix(val.trim(), 8).ok(),
+                _ => (),
+            }
+        }
+    }
+    Some((pos, flags))
+}
+
+/// Load the sockets in a process's network namespace, indexed by inode.
+fn socket_table(proc: &LinuxProcess) -> HashMap<u64, SocketInfo> {
+    let mut table = HashMap::new();
+    let tcp = [("tcp", proc.tcp()), ("tcp6", proc.tcp6())];
+    for (protocol, entries) in tcp {
+        for e in entries.into_iter().flatten() {
+            table.insert(e.inode, tcp_info(protocol, &e));
+        }
+    }
+    let udp = [("udp", proc.udp()), ("udp6", proc.udp6())];
+    for (protocol, entries) in udp {
+        for e in entries.into_iter().flatten() {
+            table.insert(e.inode, udp_info(protocol, &e));
+        }
+    }
+    for e in proc.unix().into_iter().flatten() {
+        table.insert(
+            e.inode,
+            SocketInfo {
+                protocol: "unix",
+                local: e.path.map(|p| p.display().to_string()),
+                remote: None,
+                state: Some(
+                    match e.state {
+                        UnixState::UNCONNECTED => "UNCONNECTED",
+                        UnixState::CONNECTING => "CONNECTING",
+                        UnixState::CONNECTED => "CONNECTED",
+                        UnixState::DISCONNECTING => "DISCONNECTING",
+                    }
+                    .into(),
+                ),
+            },
+        );
+    }
+    table
+}
+
+fn tcp_info(protocol: &'static str, entry: &TcpNetEntry) -> SocketInfo {
+    let state = match entry.state {
+        TcpState::Established => "ESTABLISHED",
+        TcpState::SynSent => "SYN_SENT",
+        TcpState::SynRecv | TcpState::NewSynRecv => "SYN_RECV",
+        TcpState::FinWait1 => "FIN_WAIT1",
+        TcpState::FinWait2 => "FIN_WAIT2",
+        TcpState::TimeWait => "TIME_WAIT",
+        TcpState::Close => "CLOSE",
+        TcpState::CloseWait => "CLOSE_WAIT",
+        TcpState::LastAck => "LAST_ACK",
+        TcpState::Listen => "LISTEN",
+        TcpState::Closing => "CLOSING",
+    };
+    SocketInfo {
+        protocol,
+        local: Some(entry.local_address.to_string()),
+        remote: remote_addr(&entry.remote_address),
+        state: Some(state.into()),
+    }
+}
+
+fn udp_info(protocol: &'static str, entry: &UdpNetEntry) -> SocketInfo {
+    SocketInfo {
+        protocol,
+        local: Some(entry.local_address.to_string()),
+        remote: remote_addr(&entry.remote_address),
+        state: None,
+    }
+}
+
+/// Get a remote address, if the socket is connected.
+fn remote_addr(addr: &SocketAddr) -> Option<String> {
+    if addr.ip().is_unspecified() && addr.port() == 0 {
+        None
+    } else {
+        Some(addr.to_string())
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
         util::{window_norm_u64, Diff, WindowedObservation},
         BackendError, BackendResult,
     },
-    model::DiskIO,
+    model::{DiskIO, IOTotals},
 };
 
 const BYTES_PER_SECTOR: u64 = 512;
@@ -21,6 +21,7 @@ impl Diff for DiskIO {
             name: self.name.clone(),
             rx_bytes: self.rx_bytes - previous.rx_bytes,
             tx_bytes: self.tx_bytes - previous.tx_bytes,
+            totals: self.totals,
         }
     }
 }
@@ -31,6 +32,12 @@ impl From<&DiskStat> for DiskIO {
             name: d.name.clone(),
             rx_bytes: d.sectors_read * BYTES_PER_SECTOR,
             tx_bytes: d.sectors_written * BYTES_PER_SECTOR,
+            totals: IOTotals {
+                rx_boot: d.sectors_read * BYTES_PER_SECTOR,
+                tx_boot: d.sectors_written * BYTES_PER_SECTOR,
+                rx_start: d.sectors_read * BYTES_PER_SECTOR,
+                tx_start: d.sectors_written * BYTES_PER_SECTOR,
+            },
         }
     }
 }
@@ -41,27 +48,46 @@ impl WindowedObservation for DiskIO {
             name: self.name.clone(),
             rx_bytes: window_norm_u64(self.rx_bytes, win),
             tx_bytes: window_norm_u64(self.tx_bytes, win),
+            totals: self.totals,
         }
     }
 }
 
 impl ProcFSWrapper<DiskStats> {
+    /// Add disks seen for the first time to the baseline.
+    pub(super) fn add_new_disks(DiskStats(base): &mut DiskStats, DiskStats(cur): &DiskStats) {
+        for disk in cur {
+            if !base.iter().any(|b| b.name == disk.name) {
+                base.push(disk.clone());
+            }
+        }
+    }
+
     pub(super) fn disk_stats(&self) -> BackendResult<Vec<DiskIO>> {
         let data = self.data()?;
         let DiskStats(cur) = data.current.as_ref().ok_or(BackendError::NotAvailable)?;
         let prev = data.previous.as_ref().map(|DiskStats(p)| p);
         let prev: Option<HashMap<_, _>> =
             prev.map(|v| v.iter().map(|d| (d.name.clone(), d.clone())).collect());
+        let base = data.baseline.as_ref().map(|DiskStats(b)| b);
+        let base: Option<HashMap<_, _>> =
+            base.map(|v| v.iter().map(|d| (d.name.clone(), d.clone())).collect());
         Ok(cur
             .iter()
             .map(|d| {
-                if let Some(p) = prev.as_ref().and_then(|h| h.get(&d.name)) {
+                let mut io = if let Some(p) = prev.as_ref().and_then(|h| h.get(&d.name)) {
                     let d: DiskIO = d.into();
                     let p: DiskIO = p.into();
                     d.diff(&p).normalize(data.window.window_duration())
                 } else {
                     d.into()
+                };
+                if let Some(b) = base.as_ref().and_then(|h| h.get(&d.name)) {
+                    let b: DiskIO = b.into();
+                    io.totals.rx_start = io.totals.rx_boot.saturating_sub(b.totals.rx_boot);
+                    io.totals.tx_start = io.totals.tx_boot.saturating_sub(b.totals.tx_boot);
                 }
+                io
             })
             .collect())
     }
@@ -4,7 +4,7 @@ use std::time::Duration;
 use std::{fs::File, io::BufReader};
 
 use log::*;
-use procfs::{CpuTime, KernelStats, LocalSystemInfo, ProcResult, SystemInfoInterface};
+use procfs::{CpuTime, KernelStats, LocalSystemInfo, ProcResult, SystemInfoInterface, VmStat};
 use regex::Regex;
 
 use super::data::ProcFSWrapper;
@@ -94,6 +94,24 @@ impl ProcFSWrapper<KernelStats> {
     }
 }
 
+impl ProcFSWrapper<KernelStats> {
+    /// Get the CPU time of each CPU since the last update.
+    pub(super) fn per_cpu_time_diff(&self) -> BackendResult<Vec<CpuTicks>> {
+        let data = self.data()?;
+        match (&data.current, &data.previous) {
+            // CPUs can come and go with hotplug, so only diff a consistent set
+            (Some(c), Some(p)) if c.cpu_time.len() == p.cpu_time.len() => Ok(c
+                .cpu_time
+                .iter()
+                .zip(&p.cpu_time)
+                .map(|(c, p)| c.diff(p))
+                .collect()),
+            (Some(c), _) => Ok(c.cpu_time.iter().map(|c| c.into()).collect()),
+            (None, _) => Err(BackendError::NotAvailable),
+        }
+    }
+}
+
 impl Diff for CpuTicks {
     type Difference = CpuTicks;
 
@@ -144,6 +162,21 @@ impl Diff for CpuTime {
     }
 }
 
+impl ProcFSWrapper<VmStat> {
+    /// Get the number of OOM kills since the baseline observation.
+    pub(super) fn oom_kills_since_start(&self) -> BackendResult<u64> {
+        let data = self.data()?;
+        let count = |vm: &VmStat| vm.0.get("oom_kill").copied();
+        let cur = data.current.as_ref().and_then(count);
+        let base = data.baseline.as_ref().and_then(count);
+        match (cur, base) {
+            (Some(c), Some(b)) => Ok(c.saturating_sub(b) as u64),
+            // oom_kill was added in Linux 4.13
+            _ => Err(BackendError::NotSupported),
+        }
+    }
+}
+
 pub(super) fn ticks_to_duration(ticks: u64) -> Duration {
     let tps = LocalSystemInfo.ticks_per_second();
     // fast method for common configuration
@@ -1,9 +1,11 @@
 //! Linux-specific backend with [procfs].
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::time::SystemTime;
 
 use etc_os_release::OsRelease;
 use gethostname::gethostname;
-use kernel::{read_zfs_arcstats, ZFSArcInfo};
+use kernel::{read_zfs_arcstats, CpuTicks, ZFSArcInfo};
 use log::*;
 use nix::sys::statvfs::statvfs;
 use procfs::process::Process as LinuxProcess;
@@ -11,18 +13,29 @@ use procfs::*;
 use regex::RegexSet;
 
 mod data;
+mod files;
 mod gpu;
 mod io;
 mod kernel;
 mod network;
 mod processes;
+mod sched;
 
-use super::{error::*, util::Tick, MonitorBackend};
+use super::{
+    error::*,
+    util::{window_norm_u64, RateCache, Tick, TickCache},
+    MonitorBackend,
+};
 use crate::model::cpu::LinuxCPU;
 use crate::model::*;
 use data::ProcFSWrapper;
+use files::read_open_files;
 use gpu::GPUs;
-use processes::ProcessRecord;
+use processes::{
+    read_ctx_switches, read_memory_maps, read_process_details, read_process_environ,
+    read_process_fd_usage, read_process_memory, read_process_oom, read_schedstat, send_signal,
+    ProcessRecord, ProcessRecords,
+};
 
 /// Linux-specific backend.
 pub struct LinuxBackend {
@@ -31,6 +44,9 @@ pub struct LinuxBackend {
     cpus: BackendResult<CpuInfo>,
     kernel: ProcFSWrapper<KernelStats>,
     memory: ProcFSWrapper<Meminfo>,
+    vmstat: ProcFSWrapper<VmStat>,
+    /// The last-seen OOM kill count, and when it was first seen.
+    oom_seen: RefCell<(u64, Option<SystemTime>)>,
     zfs: ProcFSWrapper<Option<ZFSArcInfo>>,
 
     load: ProcFSWrapper<LoadAverage>,
@@ -45,7 +61,21 @@ pub struct LinuxBackend {
     mounts: ProcFSWrapper<Vec<MountEntry>>,
     mount_filters: RegexSet,
 
-    processes: ProcFSWrapper<HashMap<i32, ProcessRecord>>,
+    processes: ProcessRecords,
+    threads: ProcessRecords,
+    /// Threads of the process whose threads are being listed.
+    owner_threads: RefCell<Option<(u32, ProcessRecords)>>,
+    proc_cmd: TickCache<i32, ProcessCommandInfo>,
+    proc_memory: TickCache<i32, ProcessMemory>,
+    proc_oom: TickCache<i32, ProcessOom>,
+    proc_ctx: RateCache<i32, ContextSwitches>,
+    /// Run queue delays (in nanoseconds).
+    proc_sched: RateCache<i32, u64>,
+    proc_fds: TickCache<i32, ProcessFdUsage>,
+    proc_files: TickCache<i32, Vec<OpenFile>>,
+    proc_maps: TickCache<i32, Vec<MemoryMapping>>,
+    proc_env: TickCache<i32, Vec<(String, String)>>,
+    cpu_mode: CpuPctMode,
 }
 
 impl LinuxBackend {
@@ -57,24 +87,41 @@ impl LinuxBackend {
             cpus: CpuInfo::current().map_err(|e| e.into()),
             kernel: ProcFSWrapper::for_curent_si(&tick),
             memory: ProcFSWrapper::for_current(&tick),
+            vmstat: ProcFSWrapper::for_current(&tick).with_baseline(),
+            oom_seen: RefCell::new((0, None)),
             zfs: ProcFSWrapper::new(read_zfs_arcstats, &tick),
             load: ProcFSWrapper::for_current(&tick),
             cpu_pressure: ProcFSWrapper::for_current(&tick),
             mem_pressure: ProcFSWrapper::for_current(&tick),
             io_pressure: ProcFSWrapper::for_current(&tick),
             gpu: GPUs::init()?,
-            net_ifs: ProcFSWrapper::for_current(&tick),
-            disks: ProcFSWrapper::for_current(&tick),
-            disk_filters: RegexSet::new(&[
+            net_ifs: ProcFSWrapper::for_current(&tick)
+                .with_merged_baseline(ProcFSWrapper::add_new_interfaces),
+            disks: ProcFSWrapper::for_current(&tick)
+                .with_merged_baseline(ProcFSWrapper::add_new_disks),
+            disk_filters: RegexSet::new([
                 r"^loop\d+",
                 r"^mmcblk\d+(p|boot)\d+",
+                r"^nvme\d+n\d+p\d+",
                 r"^dm-\d+",
-                r"^([sh]|xv])d[a-z]+\d+",
+                r"^([shv]|xv)d[a-z]+\d+",
             ])
             .unwrap(),
             mounts: ProcFSWrapper::new(mounts, &tick),
-            mount_filters: RegexSet::new(&["^/(dev|proc|sys|run|snap)(/|$)"]).unwrap(),
+            mount_filters: RegexSet::new(["^/(dev|proc|sys|run|snap)(/|$)"]).unwrap(),
             processes: ProcFSWrapper::new(ProcessRecord::load_all, &tick),
+            threads: ProcFSWrapper::new(ProcessRecord::load_all_threads, &tick),
+            owner_threads: RefCell::new(None),
+            proc_cmd: TickCache::with_tick(&tick),
+            proc_memory: TickCache::with_tick(&tick),
+            proc_oom: TickCache::with_tick(&tick),
+            proc_ctx: RateCache::with_tick(&tick),
+            proc_sched: RateCache::with_tick(&tick),
+            proc_fds: TickCache::with_tick(&tick),
+            proc_files: TickCache::with_tick(&tick),
+            proc_maps: TickCache::with_tick(&tick),
+            proc_env: TickCache::with_tick(&tick),
+            cpu_mode: CpuPctMode::PerCore,
         })
     }
 }
@@ -91,6 +138,23 @@ impl LinuxBackend {
     }
 }
 
+/// Compute CPU usage from the ticks spent in each state.
+fn cpu_usage(cpu: &CpuTicks) -> CPU {
+    let tot = cpu.total as f32;
+    CPU {
+        utilization: cpu.total_used as f32 / tot,
+        extended: cpu::ExtendedCPU::Linux(LinuxCPU {
+            user: cpu.user as f32 / tot,
+            system: cpu.system as f32 / tot,
+            iowait: cpu.iowait.unwrap_or_default() as f32 / tot,
+            idle: cpu.idle as f32 / tot,
+            irq: cpu.irq.unwrap_or_default() as f32 / tot,
+            nice: cpu.nice as f32 / tot,
+            steal: cpu.steal.unwrap_or_default() as f32 / tot,
+        }),
+    }
+}
+
 impl MonitorBackend for LinuxBackend {
     fn update(&mut self, _opts: &Options) -> BackendResult<()> {
         self.tick.advance();
@@ -98,6 +162,10 @@ impl MonitorBackend for LinuxBackend {
         Ok(())
     }
 
+    fn set_cpu_mode(&mut self, mode: CpuPctMode) {
+        self.cpu_mode = mode;
+    }
+
     fn hostname(&self) -> BackendResult<String> {
         Ok(gethostname().to_string_lossy().into())
     }
@@ -122,20 +190,12 @@ impl MonitorBackend for LinuxBackend {
 
     fn global_cpu(&self) -> BackendResult<CPU> {
         let cpu = self.kernel.cpu_time_diff()?;
-        let tot = cpu.total as f32;
-
-        Ok(CPU {
-            utilization: cpu.total_used as f32 / tot,
-            extended: cpu::ExtendedCPU::Linux(LinuxCPU {
-                user: cpu.user as f32 / tot,
-                system: cpu.system as f32 / tot,
-                iowait: cpu.iowait.unwrap_or_default() as f32 / tot,
-                idle: cpu.idle as f32 / tot,
-                irq: cpu.irq.unwrap_or_default() as f32 / tot,
-                nice: cpu.nice as f32 / tot,
-                steal: cpu.steal.unwrap_or_default() as f32 / tot,
-            }),
-        })
+        Ok(cpu_usage(&cpu))
+    }
+
+    fn cpus(&self) -> BackendResult<Vec<CPU>> {
+        let cpus = self.kernel.per_cpu_time_diff()?;
+        Ok(cpus.iter().map(cpu_usage).collect())
     }
 
     fn memory(&self) -> BackendResult<Memory> {
@@ -161,7 +221,7 @@ impl MonitorBackend for LinuxBackend {
             },
             free: mem.mem_free,
             total: mem.mem_total,
-            extended: ExtendedMemory::Linux(memory::LinuxMemory {
+            extended: ExtendedMemory::Linux(Box::new(memory::LinuxMemory {
                 active: mem.active,
                 inactive: mem.inactive,
                 buffers: mem.buffers,
@@ -169,7 +229,18 @@ impl MonitorBackend for LinuxBackend {
                 shared: mem.shmem,
                 reclaimable: mem.s_reclaimable,
                 arc,
-            }),
+                swap_cached: mem.swap_cached,
+                dirty: mem.dirty,
+                writeback: mem.writeback,
+                anon: mem.anon_pages,
+                mapped: mem.mapped,
+                slab: mem.slab,
+                kernel_stack: mem.kernel_stack,
+                page_tables: mem.page_tables,
+                unevictable: mem.unevictable,
+                committed: mem.committed_as,
+                commit_limit: mem.commit_limit,
+            })),
         })
     }
 
@@ -230,6 +301,18 @@ impl MonitorBackend for LinuxBackend {
         })
     }
 
+    fn oom_kills(&self) -> BackendResult<OomKills> {
+        let count = self.vmstat.oom_kills_since_start()?;
+        let mut seen = self.oom_seen.borrow_mut();
+        if count > seen.0 {
+            *seen = (count, Some(SystemTime::now()));
+        }
+        Ok(OomKills {
+            count,
+            last: seen.1,
+        })
+    }
+
     fn gpus(&self) -> BackendResult<Vec<GPUStats>> {
         self.gpu.gpus()
     }
@@ -238,44 +321,121 @@ impl MonitorBackend for LinuxBackend {
         self.gpu.gpu_count() > 0
     }
 
-    fn processes<'a>(&'a self) -> BackendResult<Vec<Process>> {
-        let data = self.processes.data()?;
-        let cur = data.current.as_ref().ok_or(BackendError::NotAvailable)?;
-        let prev = data.previous.as_ref();
-        let cpu = self.kernel.cpu_time_diff()?;
-        let mem = self.memory.current()?;
+    fn processes(&self) -> BackendResult<Vec<Process>> {
+        self.process_list(&self.processes)
+    }
 
-        let mut procs = Vec::new();
-        for (pid, cp) in cur.iter() {
-            let op = prev.and_then(|m| m.get(pid));
-            if let Ok(proc) = self.process_info(cp, op, &cpu, &*mem) {
-                procs.push(proc)
-            }
+    fn threads(&self) -> BackendResult<Vec<Process>> {
+        self.process_list(&self.threads)
+    }
+
+    fn process_threads(&self, pid: u32) -> BackendResult<Vec<Process>> {
+        let mut owner = self.owner_threads.borrow_mut();
+        if owner.as_ref().is_none_or(|(owner, _)| *owner != pid) {
+            let tgid = pid as i32;
+            let threads = ProcFSWrapper::new(move || ProcessRecord::load_threads(tgid), &self.tick);
+            *owner = Some((pid, threads));
         }
+        match owner.as_ref() {
+            Some((_, threads)) => self.process_list(threads),
+            None => Err(BackendError::NotAvailable),
+        }
+    }
 
-        Ok(procs)
+    fn process_churn(&self) -> BackendResult<ProcessChurn> {
+        self.record_churn(&self.processes)
     }
 
     fn process_cmd_info(&self, pid: u32) -> BackendResult<ProcessCommandInfo> {
-        let proc = LinuxProcess::new(pid as i32)?;
-        Ok(ProcessCommandInfo {
-            exe: proc.exe()?.to_string_lossy().to_string(),
-            cmdline: proc.cmdline()?,
+        // filters look up every process's command line, possibly several times per frame
+        self.proc_cmd.get_or_load(pid as i32, || {
+            let proc = LinuxProcess::new(pid as i32)?;
+            Ok(ProcessCommandInfo {
+                exe: proc.exe()?.to_string_lossy().to_string(),
+                cmdline: proc.cmdline()?,
+            })
         })
     }
 
-    fn networks(&self) -> BackendResult<Vec<NetworkStats>> {
-        let nets = self.net_ifs.network_usage()?;
-        Ok(nets
-            .into_iter()
-            .map(|n| NetworkStats {
-                name: n.name,
-                rx_bytes: n.recv_bytes,
-                rx_packets: n.recv_packets,
-                tx_bytes: n.sent_bytes,
-                tx_packets: n.sent_packets,
+    fn process_memory(&self, pid: u32) -> BackendResult<ProcessMemory> {
+        self.proc_memory
+            .get_or_load(pid as i32, || read_process_memory(pid as i32))
+    }
+
+    fn process_memory_maps(&self, pid: u32) -> BackendResult<Vec<MemoryMapping>> {
+        self.proc_maps
+            .get_or_load(pid as i32, || read_memory_maps(pid as i32))
+    }
+
+    fn process_oom(&self, pid: u32) -> BackendResult<ProcessOom> {
+        self.proc_oom
+            .get_or_load(pid as i32, || read_process_oom(pid as i32))
+    }
+
+    fn process_ctx_switches(&self, pid: u32) -> BackendResult<ContextSwitches> {
+        let load = || read_ctx_switches(pid as i32);
+        self.proc_ctx
+            .rate(pid as i32, load, |cur, prev, window| ContextSwitches {
+                voluntary: window_norm_u64(cur.voluntary.saturating_sub(prev.voluntary), window),
+                involuntary: window_norm_u64(
+                    cur.involuntary.saturating_sub(prev.involuntary),
+                    window,
+                ),
             })
-            .collect())
+    }
+
+    fn process_sched_wait(&self, pid: u32) -> BackendResult<f32> {
+        let load = || read_schedstat(pid as i32).map(|s| s.run_delay);
+        self.proc_sched.rate(pid as i32, load, |cur, prev, window| {
+            (cur.saturating_sub(*prev) as f64 / window.as_nanos() as f64) as f32
+        })
+    }
+
+    fn process_open_files(&self, pid: u32) -> BackendResult<Vec<OpenFile>> {
+        self.proc_files
+            .get_or_load(pid as i32, || read_open_files(pid as i32))
+    }
+
+    fn process_environ(&self, pid: u32) -> BackendResult<Vec<(String, String)>> {
+        self.proc_env
+            .get_or_load(pid as i32, || read_process_environ(pid as i32))
+    }
+
+    fn process_details(&self, pid: u32) -> BackendResult<ProcessDetails> {
+        read_process_details(pid as i32)
+    }
+
+    fn process_fd_usage(&self, pid: u32) -> BackendResult<ProcessFdUsage> {
+        self.proc_fds
+            .get_or_load(pid as i32, || read_process_fd_usage(pid as i32))
+    }
+
+    fn process_io_priority(&self, pid: u32) -> BackendResult<IOPriority> {
+        sched::io_priority(pid as i32)
+    }
+
+    fn process_affinity(&self, pid: u32) -> BackendResult<Vec<u32>> {
+        sched::affinity(pid as i32)
+    }
+
+    fn signal_process(&self, pid: u32, signal: ProcessSignal) -> BackendResult<()> {
+        send_signal(pid as i32, signal)
+    }
+
+    fn set_process_nice(&self, pid: u32, nice: i32) -> BackendResult<()> {
+        sched::set_nice(pid as i32, nice)
+    }
+
+    fn set_process_io_priority(&self, pid: u32, prio: IOPriority) -> BackendResult<()> {
+        sched::set_io_priority(pid as i32, prio)
+    }
+
+    fn set_process_affinity(&self, pid: u32, cpus: &[u32]) -> BackendResult<()> {
+        sched::set_affinity(pid as i32, cpus)
+    }
+
+    fn networks(&self) -> BackendResult<Vec<NetworkStats>> {
+        self.net_ifs.network_usage()
     }
 
     fn disks(&self) -> BackendResult<Vec<DiskIO>> {
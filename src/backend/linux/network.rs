@@ -5,6 +5,7 @@ use crate::backend::{
     util::{window_norm_u64, Diff, WindowedObservation},
     BackendError, BackendResult,
 };
+use crate::model::{IOTotals, NetworkStats};
 
 impl Diff for DeviceStatus {
     type Difference = Self;
@@ -57,17 +58,48 @@ impl WindowedObservation for DeviceStatus {
 }
 
 impl ProcFSWrapper<InterfaceDeviceStatus> {
-    pub(super) fn network_usage(&self) -> BackendResult<Vec<DeviceStatus>> {
+    /// Add interfaces seen for the first time to the baseline.
+    pub(super) fn add_new_interfaces(
+        InterfaceDeviceStatus(base): &mut InterfaceDeviceStatus,
+        InterfaceDeviceStatus(cur): &InterfaceDeviceStatus,
+    ) {
+        for (name, dev) in cur {
+            if !base.contains_key(name) {
+                base.insert(name.clone(), dev.clone());
+            }
+        }
+    }
+
+    pub(super) fn network_usage(&self) -> BackendResult<Vec<NetworkStats>> {
         let data = self.data()?;
         let InterfaceDeviceStatus(cur) = data.current.as_ref().ok_or(BackendError::NotAvailable)?;
         let prev = data.previous.as_ref().map(|InterfaceDeviceStatus(p)| p);
+        let base = data.baseline.as_ref().map(|InterfaceDeviceStatus(b)| b);
         Ok(cur
             .values()
             .map(|n| {
-                if let Some(p) = prev.and_then(|h| h.get(&n.name)) {
+                let rate = if let Some(p) = prev.and_then(|h| h.get(&n.name)) {
                     n.diff(p).normalize(data.window.window_duration())
                 } else {
                     n.clone()
+                };
+                let b = base.and_then(|h| h.get(&n.name));
+                NetworkStats {
+                    name: rate.name,
+                    rx_bytes: rate.recv_bytes,
+                    rx_packets: rate.recv_packets,
+                    tx_bytes: rate.sent_bytes,
+                    tx_packets: rate.sent_packets,
+                    totals: IOTotals {
+                        rx_boot: n.recv_bytes,
+                        tx_boot: n.sent_bytes,
+                        rx_start: n
+                            .recv_bytes
+                            .saturating_sub(b.map(|b| b.recv_bytes).unwrap_or_default()),
+                        tx_start: n
+                            .sent_bytes
+                            .saturating_sub(b.map(|b| b.sent_bytes).unwrap_or_default()),
+                    },
                 }
             })
             .collect())
@@ -1,20 +1,43 @@
 //! Linux process code.
-use std::{collections::HashMap, time::Instant};
+use std::{
+    collections::HashMap,
+    fs,
+    time::{Duration, Instant, SystemTime},
+};
 
 use log::*;
-use procfs::process::{all_processes, Io, Stat};
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+use procfs::process::{
+    all_processes, Io, Limit, LimitValue, MMPermissions, MMapPath, Process as LinuxProcess,
+    Schedstat, Stat,
+};
 use procfs::{Meminfo, ProcResult, WithCurrentSystemInfo};
 
 use crate::backend::linux::kernel::ticks_to_duration;
-use crate::backend::util::window_norm_u64;
-use crate::backend::{BackendResult, MonitorBackend};
-use crate::model::Process;
+use crate::backend::util::{window_norm_f32, window_norm_u64};
+use crate::backend::{BackendError, BackendResult, MonitorBackend};
+use crate::model::maps::{MapCategory, MemoryMapping};
+use crate::model::process::{ResourceLimit, SchedPolicy};
+use crate::model::{
+    ContextSwitches, CpuPctMode, Process, ProcessChange, ProcessChurn, ProcessDetails,
+    ProcessFdUsage, ProcessMemory, ProcessOom, ProcessSignal,
+};
 
+use super::data::ProcFSWrapper;
 use super::kernel::CpuTicks;
 use super::LinuxBackend;
 
+/// Process flag marking kernel threads (from `linux/sched.h`).
+const PF_KTHREAD: u32 = 0x00200000;
+
+/// Snapshots of process (or thread) records.
+pub(super) type ProcessRecords = ProcFSWrapper<HashMap<i32, ProcessRecord>>;
+
 pub(super) struct ProcessRecord {
     pub pid: i32,
+    /// The owning process, if this record is a thread.
+    pub tgid: Option<i32>,
     pub uid: Option<u32>,
     pub stat: Stat,
     pub io: Option<Io>,
@@ -44,6 +67,7 @@ impl ProcessRecord {
                 proc.pid,
                 ProcessRecord {
                     pid: proc.pid,
+                    tgid: None,
                     uid: proc.uid().ok(),
                     stat,
                     io,
@@ -53,9 +77,374 @@ impl ProcessRecord {
         }
         Ok(procs)
     }
+
+    /// Load the individual threads of all processes, keyed by thread ID.
+    pub(super) fn load_all_threads() -> ProcResult<HashMap<i32, ProcessRecord>> {
+        let mut threads = HashMap::new();
+        for proc in all_processes()? {
+            let proc = match proc {
+                Ok(p) => p,
+                Err(e) => {
+                    warn!("error fetching process: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = Self::load_tasks(&proc, &mut threads) {
+                warn!("process {}: error listing tasks: {}", proc.pid, e);
+            }
+        }
+        Ok(threads)
+    }
+
+    /// Load the threads of a single process, keyed by thread ID.
+    pub(super) fn load_threads(pid: i32) -> ProcResult<HashMap<i32, ProcessRecord>> {
+        let mut threads = HashMap::new();
+        Self::load_tasks(&LinuxProcess::new(pid)?, &mut threads)?;
+        Ok(threads)
+    }
+
+    fn load_tasks(
+        proc: &LinuxProcess,
+        threads: &mut HashMap<i32, ProcessRecord>,
+    ) -> ProcResult<()> {
+        let uid = proc.uid().ok();
+        // threads come and go quickly, so skip any that disappear mid-read
+        for task in proc.tasks()?.flatten() {
+            let Ok(stat) = task.stat() else {
+                continue;
+            };
+            threads.insert(
+                task.tid,
+                ProcessRecord {
+                    pid: task.tid,
+                    tgid: Some(task.pid),
+                    uid,
+                    stat,
+                    io: task.io().ok(),
+                    fetched: Instant::now(),
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Read the scheduler statistics for a process (or thread).  The process-level
+/// `schedstat` only covers the main thread, so for multithreaded processes we sum
+/// the tasks.
+pub(super) fn read_schedstat(pid: i32) -> BackendResult<Schedstat> {
+    let proc = LinuxProcess::new(pid)?;
+    let status = proc.status()?;
+    // a thread's own `schedstat` covers just that thread
+    if status.tgid != pid || status.threads <= 1 {
+        return Ok(proc.schedstat()?);
+    }
+    let mut total = Schedstat {
+        sum_exec_runtime: 0,
+        run_delay: 0,
+        pcount: 0,
+    };
+    for task in proc.tasks()? {
+        // threads may exit while we are reading them
+        if let Ok(ts) = task.and_then(|t| t.schedstat()) {
+            total.sum_exec_runtime += ts.sum_exec_runtime;
+            total.run_delay += ts.run_delay;
+            total.pcount += ts.pcount;
+        }
+    }
+    Ok(total)
+}
+
+/// Read detailed memory usage for a process from `smaps_rollup`, falling back to
+/// the swap usage in `status` if the rollup is unavailable.
+pub(super) fn read_process_memory(pid: i32) -> BackendResult<ProcessMemory> {
+    let proc = LinuxProcess::new(pid)?;
+    match proc.smaps_rollup() {
+        Ok(rollup) => {
+            let map = rollup
+                .memory_map_rollup
+                .0
+                .into_iter()
+                .next()
+                .ok_or(BackendError::NotAvailable)?
+                .extension
+                .map;
+            let get = |k: &str| map.get(k).copied();
+            let sum = |k1: &str, k2: &str| get(k1).zip(get(k2)).map(|(a, b)| a + b);
+            Ok(ProcessMemory {
+                pss: get("Pss"),
+                uss: sum("Private_Clean", "Private_Dirty"),
+                shared: sum("Shared_Clean", "Shared_Dirty"),
+                swap: get("Swap"),
+            })
+        }
+        Err(e) => {
+            trace!("process {}: smaps_rollup unavailable: {}", pid, e);
+            let status = proc.status()?;
+            Ok(ProcessMemory {
+                swap: status.vmswap.map(|kb| kb * 1024),
+                ..ProcessMemory::default()
+            })
+        }
+    }
+}
+
+/// Read the memory mappings of a process.
+pub(super) fn read_memory_maps(pid: i32) -> BackendResult<Vec<MemoryMapping>> {
+    let proc = LinuxProcess::new(pid)?;
+    let maps = proc.smaps()?;
+    let exe = proc.exe().ok();
+    Ok(maps
+        .into_iter()
+        .map(|map| {
+            let get = |k: &str| map.extension.map.get(k).copied().unwrap_or_default();
+            let perms = [
+                (MMPermissions::READ, 'r'),
+                (MMPermissions::WRITE, 'w'),
+                (MMPermissions::EXECUTE, 'x'),
+            ]
+            .iter()
+            .map(|(p, c)| if map.perms.contains(*p) { *c } else { '-' })
+            .chain([if map.perms.contains(MMPermissions::SHARED) {
+                's'
+            } else {
+                'p'
+            }])
+            .collect();
+            let (category, path) = match &map.pathname {
+                MMapPath::Path(p) if Some(p) == exe.as_ref() => {
+                    (MapCategory::Program, Some(p.display().to_string()))
+                }
+                path => map_category(path),
+            };
+            MemoryMapping {
+                start: map.address.0,
+                size: map.address.1 - map.address.0,
+                perms,
+                category,
+                path,
+                rss: get("Rss"),
+                pss: get("Pss"),
+                swap: get("Swap"),
+            }
+        })
+        .collect())
+}
+
+/// Classify a mapping by its path.
+fn map_category(path: &MMapPath) -> (MapCategory, Option<String>) {
+    match path {
+        MMapPath::Heap => (MapCategory::Heap, None),
+        MMapPath::Stack => (MapCategory::Stack, None),
+        MMapPath::TStack(tid) => (MapCategory::Stack, Some(format!("[stack:{}]", tid))),
+        MMapPath::Anonymous => (MapCategory::Anonymous, None),
+        MMapPath::Vsys(key) => (MapCategory::SharedMemory, Some(format!("SYSV{:08x}", key))),
+        MMapPath::Path(p) => {
+            let name = p.display().to_string();
+            let cat = if name.starts_with("/dev/shm/")
+                || name.starts_with("/memfd:")
+                || name.starts_with("/SYSV")
+                || name.starts_with("/dev/zero")
+            {
+                // /dev/zero mappings are shared anonymous memory
+                MapCategory::SharedMemory
+            } else if name.starts_with("/dev/") {
+                MapCategory::Device
+            } else if name.ends_with(".so") || name.contains(".so.") {
+                MapCategory::SharedLib
+            } else {
+                MapCategory::File
+            };
+            (cat, Some(name))
+        }
+        MMapPath::Other(name) => {
+            let cat = if name.starts_with("[anon_shmem") {
+                MapCategory::SharedMemory
+            } else if name.starts_with("[anon") {
+                MapCategory::Anonymous
+            } else if name.starts_with("anon_inode:") {
+                // DRM buffers and other driver mappings
+                MapCategory::Device
+            } else {
+                MapCategory::Other
+            };
+            (cat, Some(name.clone()))
+        }
+        MMapPath::Vdso => (MapCategory::Other, Some("[vdso]".into())),
+        MMapPath::Vvar => (MapCategory::Other, Some("[vvar]".into())),
+        MMapPath::Vsyscall => (MapCategory::Other, Some("[vsyscall]".into())),
+        MMapPath::Rollup => (MapCategory::Other, Some("[rollup]".into())),
+    }
+}
+
+/// Read the OOM killer scores for a process.
+pub(super) fn read_process_oom(pid: i32) -> BackendResult<ProcessOom> {
+    let proc = LinuxProcess::new(pid)?;
+    Ok(ProcessOom {
+        score: proc.oom_score()? as u32,
+        adj: proc.oom_score_adj()? as i32,
+    })
+}
+
+/// Read the context switch counts for a process (or thread) from `status`.
+pub(super) fn read_ctx_switches(pid: i32) -> BackendResult<ContextSwitches> {
+    let status = LinuxProcess::new(pid)?.status()?;
+    match (
+        status.voluntary_ctxt_switches,
+        status.nonvoluntary_ctxt_switches,
+    ) {
+        (Some(voluntary), Some(involuntary)) => Ok(ContextSwitches {
+            voluntary,
+            involuntary,
+        }),
+        _ => Err(BackendError::NotAvailable),
+    }
+}
+
+/// Read miscellaneous details for a process.
+///
+/// Individual fields the user is not allowed to read (e.g. another user's
+/// working directory) are left empty.
+pub(super) fn read_process_details(pid: i32) -> BackendResult<ProcessDetails> {
+    let proc = LinuxProcess::new(pid)?;
+    let io = proc.io().ok();
+    // prefer the unified (v2) hierarchy, which has ID 0
+    let cgroup = proc.cgroups().ok().and_then(|cgs| {
+        cgs.0
+            .iter()
+            .min_by_key(|cg| cg.hierarchy)
+            .map(|cg| cg.pathname.clone())
+    });
+    Ok(ProcessDetails {
+        cwd: proc.cwd().ok().map(|p| p.display().to_string()),
+        gid: proc.status().ok().map(|s| s.rgid),
+        cgroup,
+        io_read_total: io.map(|io| io.read_bytes),
+        io_write_total: io.map(|io| io.write_bytes),
+        env_count: proc.environ().ok().map(|env| env.len()),
+    })
+}
+
+/// Read the environment of a process.
+///
+/// Other users' environments are private, so this usually fails with
+/// [BackendError::NotAllowed] for them.
+pub(super) fn read_process_environ(pid: i32) -> BackendResult<Vec<(String, String)>> {
+    let proc = LinuxProcess::new(pid)?;
+    let mut env: Vec<_> = proc
+        .environ()?
+        .into_iter()
+        .map(|(k, v)| {
+            (
+                k.to_string_lossy().to_string(),
+                v.to_string_lossy().to_string(),
+            )
+        })
+        .collect();
+    env.sort();
+    Ok(env)
+}
+
+/// Read the file descriptor usage and limits for a process.
+pub(super) fn read_process_fd_usage(pid: i32) -> BackendResult<ProcessFdUsage> {
+    let proc = LinuxProcess::new(pid)?;
+    let limits = proc.limits()?;
+    Ok(ProcessFdUsage {
+        // procfs' fallback for older kernels counts the `.` and `..` entries
+        open: fs::read_dir(format!("/proc/{pid}/fd"))?.count() as u64,
+        nofile: limits.max_open_files.into(),
+        nproc: limits.max_processes.into(),
+    })
+}
+
+/// Send a signal to a process.
+pub(super) fn send_signal(pid: i32, signal: ProcessSignal) -> BackendResult<()> {
+    let signal = match signal {
+        ProcessSignal::Term => Signal::SIGTERM,
+        ProcessSignal::Kill => Signal::SIGKILL,
+        ProcessSignal::Int => Signal::SIGINT,
+        ProcessSignal::Hup => Signal::SIGHUP,
+        ProcessSignal::Stop => Signal::SIGSTOP,
+        ProcessSignal::Cont => Signal::SIGCONT,
+        ProcessSignal::Usr1 => Signal::SIGUSR1,
+        ProcessSignal::Usr2 => Signal::SIGUSR2,
+    };
+    kill(Pid::from_raw(pid), signal)?;
+    Ok(())
+}
+
+impl From<Limit> for ResourceLimit {
+    fn from(limit: Limit) -> Self {
+        let value = |v| match v {
+            LimitValue::Value(v) => Some(v),
+            LimitValue::Unlimited => None,
+        };
+        ResourceLimit {
+            soft: value(limit.soft_limit),
+            hard: value(limit.hard_limit),
+        }
+    }
 }
 
 impl LinuxBackend {
+    /// Convert a process or thread snapshot into the process list.
+    pub(super) fn process_list(&self, records: &ProcessRecords) -> BackendResult<Vec<Process>> {
+        let data = records.data()?;
+        let cur = data.current.as_ref().ok_or(BackendError::NotAvailable)?;
+        let prev = data.previous.as_ref();
+        let cpu = self.kernel.cpu_time_diff()?;
+        let mem = self.memory.current()?;
+
+        let mut procs = Vec::new();
+        for (pid, cp) in cur.iter() {
+            // a different start time means the PID has been reused
+            let op = prev
+                .and_then(|m| m.get(pid))
+                .filter(|op| op.stat.starttime == cp.stat.starttime);
+            if let Ok(mut proc) = self.process_info(cp, op, &cpu, &mem) {
+                if prev.is_some() && op.is_none() {
+                    proc.change = ProcessChange::Started;
+                }
+                procs.push(proc)
+            }
+        }
+        // keep exited processes for one more refresh, so short-lived ones are visible
+        for (_, pp) in prev.iter().flat_map(|m| m.iter()) {
+            if cur.contains_key(&pp.pid) {
+                continue;
+            }
+            if let Ok(mut proc) = self.process_info(pp, None, &cpu, &mem) {
+                // without a later sample, the I/O counters are lifetime totals
+                proc.io_read = proc.io_read.map(|_| 0);
+                proc.io_write = proc.io_write.map(|_| 0);
+                proc.change = ProcessChange::Exited;
+                procs.push(proc)
+            }
+        }
+
+        Ok(procs)
+    }
+
+    /// Get the rates of processes starting and exiting in a process snapshot.
+    pub(super) fn record_churn(&self, records: &ProcessRecords) -> BackendResult<ProcessChurn> {
+        let data = records.data()?;
+        let cur = data.current.as_ref().ok_or(BackendError::NotAvailable)?;
+        let prev = data.previous.as_ref().ok_or(BackendError::NotAvailable)?;
+        let started = cur
+            .iter()
+            .filter(|(pid, cp)| {
+                prev.get(pid)
+                    .is_none_or(|op| op.stat.starttime != cp.stat.starttime)
+            })
+            .count();
+        let exited = prev.keys().filter(|pid| !cur.contains_key(pid)).count();
+        let window = data.window.window_duration();
+        Ok(ProcessChurn {
+            started: window_norm_f32(started as f32, window),
+            exited: window_norm_f32(exited as f32, window),
+        })
+    }
+
     pub(super) fn process_info(
         &self,
         cur: &ProcessRecord,
@@ -65,13 +454,27 @@ impl LinuxBackend {
     ) -> BackendResult<Process> {
         trace!("looking up process {}", cur.pid);
         let time = cur.stat.utime + cur.stat.stime;
-        let ncpus = self.cpu_count()?;
+        // CPU ticks are summed over all logical CPUs
+        let scale = match self.cpu_mode {
+            CpuPctMode::PerCore => self.logical_cpu_count()?,
+            CpuPctMode::OfTotal => 1,
+        };
         let rss = cur.stat.rss_bytes().get();
+        let boot = SystemTime::UNIX_EPOCH + Duration::from_secs(self.kernel.current()?.btime);
+        // secondary threads are children of their process's main thread
+        let ppid = match cur.tgid {
+            Some(tgid) if tgid != cur.pid => tgid,
+            _ => cur.stat.ppid,
+        };
         let mut proc = Process {
             pid: cur.pid as u32,
-            ppid: Some(cur.stat.ppid as u32),
+            ppid: Some(ppid as u32),
+            tgid: cur.tgid.map(|t| t as u32),
             name: cur.stat.comm.clone(),
             uid: cur.uid,
+            change: ProcessChange::Unchanged,
+            kernel_thread: cur.stat.flags & PF_KTHREAD != 0 || cur.pid == 2 || ppid == 2,
+            start_time: Some(boot + ticks_to_duration(cur.stat.starttime)),
             status: cur.stat.state,
             cpu_util: 0.0,
             cpu_time: Some(ticks_to_duration(time)),
@@ -80,8 +483,21 @@ impl LinuxBackend {
             mem_util: rss as f32 / mem.mem_total as f32,
             mem_rss: rss,
             mem_virt: cur.stat.vsize,
+            mem_detail: None,
             io_read: None,
             io_write: None,
+            minor_faults: None,
+            major_faults: None,
+            vol_ctx_switches: None,
+            invol_ctx_switches: None,
+            sched_wait: None,
+            threads: Some(cur.stat.num_threads as u32),
+            priority: Some(cur.stat.priority as i32),
+            nice: Some(cur.stat.nice as i32),
+            policy: cur.stat.policy.map(SchedPolicy::from_linux),
+            rt_priority: cur.stat.rt_priority,
+            processor: cur.stat.processor.map(|p| p as u32),
+            tree: None,
         };
         if let Some(io) = cur.io {
             proc.io_read = Some(io.read_bytes);
@@ -98,8 +514,16 @@ impl LinuxBackend {
                     .io_write
                     .map(|b| window_norm_u64(b - io.write_bytes, delta_t));
             }
+            proc.minor_faults = Some(window_norm_u64(
+                cur.stat.minflt.saturating_sub(prev.stat.minflt),
+                delta_t,
+            ));
+            proc.major_faults = Some(window_norm_u64(
+                cur.stat.majflt.saturating_sub(prev.stat.majflt),
+                delta_t,
+            ));
             let tdiff = time - pt;
-            proc.cpu_util = (tdiff * ncpus as u64) as f32 / cpu.total as f32;
+            proc.cpu_util = (tdiff * scale as u64) as f32 / cpu.total as f32;
         }
         Ok(proc)
     }
@@ -0,0 +1,90 @@
+//! Linux process scheduling controls.
+use nix::errno::Errno;
+use nix::sched::{sched_getaffinity, sched_setaffinity, CpuSet};
+use nix::unistd::Pid;
+use procfs::process::Process as LinuxProcess;
+
+use crate::backend::BackendResult;
+use crate::model::process::{IOClass, IOPriority};
+
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+const IOPRIO_CLASS_SHIFT: u32 = 13;
+const IOPRIO_PRIO_MASK: libc::c_int = (1 << IOPRIO_CLASS_SHIFT) - 1;
+
+/// Apply a scheduling change to every thread of a process, like `renice -g`
+/// or `taskset -a`. A TID that isn't a thread group leader is changed alone.
+fn for_each_task(pid: i32, apply: impl Fn(i32) -> BackendResult<()>) -> BackendResult<()> {
+    apply(pid)?;
+    let proc = LinuxProcess::new(pid)?;
+    if proc.status()?.tgid != pid {
+        return Ok(());
+    }
+    // threads can exit between listing and changing them, so only the leader's
+    // result matters
+    for task in proc.tasks()?.flatten().filter(|t| t.tid != pid) {
+        let _ = apply(task.tid);
+    }
+    Ok(())
+}
+
+/// Set the nice value of every thread in a process.
+pub(super) fn set_nice(pid: i32, nice: i32) -> BackendResult<()> {
+    for_each_task(pid, |tid| {
+        // SAFETY: setpriority has no memory-safety preconditions
+        let rc = unsafe { libc::setpriority(libc::PRIO_PROCESS, tid as libc::id_t, nice) };
+        Errno::result(rc)?;
+        Ok(())
+    })
+}
+
+/// Get a process's I/O scheduling class and priority.
+pub(super) fn io_priority(pid: i32) -> BackendResult<IOPriority> {
+    // SAFETY: ioprio_get only takes integer arguments
+    let rc = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid) };
+    let prio = Errno::result(rc)? as libc::c_int;
+    let level = (prio & IOPRIO_PRIO_MASK) as u8;
+    Ok(match prio >> IOPRIO_CLASS_SHIFT {
+        1 => IOPriority::new(IOClass::RealTime, level),
+        2 => IOPriority::new(IOClass::BestEffort, level),
+        3 => IOPriority::new(IOClass::Idle, 0),
+        _ => IOPriority::new(IOClass::None, 0),
+    })
+}
+
+/// Set the I/O scheduling class and priority of every thread in a process.
+pub(super) fn set_io_priority(pid: i32, prio: IOPriority) -> BackendResult<()> {
+    let class = match prio.class {
+        IOClass::None => 0,
+        IOClass::RealTime => 1,
+        IOClass::BestEffort => 2,
+        IOClass::Idle => 3,
+    };
+    let value = (class << IOPRIO_CLASS_SHIFT) | prio.level as libc::c_int;
+    for_each_task(pid, |tid| {
+        // SAFETY: ioprio_set only takes integer arguments
+        let rc = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, tid, value) };
+        Errno::result(rc)?;
+        Ok(())
+    })
+}
+
+/// Get the CPUs a process may run on.
+pub(super) fn affinity(pid: i32) -> BackendResult<Vec<u32>> {
+    let set = sched_getaffinity(Pid::from_raw(pid))?;
+    Ok((0..CpuSet::count())
+        .filter(|c| set.is_set(*c).unwrap_or(false))
+        .map(|c| c as u32)
+        .collect())
+}
+
+/// Set the CPUs every thread in a process may run on.
+pub(super) fn set_affinity(pid: i32, cpus: &[u32]) -> BackendResult<()> {
+    let mut set = CpuSet::new();
+    for cpu in cpus {
+        set.set(*cpu as usize)?;
+    }
+    for_each_task(pid, |tid| {
+        sched_setaffinity(Pid::from_raw(tid), &set)?;
+        Ok(())
+    })
+}
@@ -17,6 +17,9 @@ pub trait MonitorBackend {
     /// Refresh the system status data.
     fn update(&mut self, opts: &Options) -> BackendResult<()>;
 
+    /// Set the scale of the CPU utilization reported for processes.
+    fn set_cpu_mode(&mut self, mode: CpuPctMode);
+
     /// Get the hostname
     fn hostname(&self) -> BackendResult<String>;
 
@@ -35,6 +38,11 @@ pub trait MonitorBackend {
     /// Get overall CPU utilization.
     fn global_cpu(&self) -> BackendResult<CPU>;
 
+    /// Get the utilization of each logical CPU.
+    fn cpus(&self) -> BackendResult<Vec<CPU>> {
+        Err(BackendError::NotSupported)
+    }
+
     /// Get memory usage.
     fn memory(&self) -> BackendResult<Memory>;
 
@@ -49,8 +57,32 @@ pub trait MonitorBackend {
         Err(BackendError::NotSupported)
     }
 
+    /// Get the processes killed by the OOM killer since startup.
+    fn oom_kills(&self) -> BackendResult<OomKills> {
+        Err(BackendError::NotSupported)
+    }
+
     /// Get the running processes.
-    fn processes<'a>(&'a self) -> BackendResult<Vec<Process>> {
+    fn processes(&self) -> BackendResult<Vec<Process>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the individual threads of all running processes.
+    fn threads(&self) -> BackendResult<Vec<Process>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the threads of a single process.
+    fn process_threads(&self, pid: u32) -> BackendResult<Vec<Process>> {
+        Ok(self
+            .threads()?
+            .into_iter()
+            .filter(|t| t.tgid == Some(pid))
+            .collect())
+    }
+
+    /// Get the rates at which processes are starting and exiting.
+    fn process_churn(&self) -> BackendResult<ProcessChurn> {
         Err(BackendError::NotSupported)
     }
 
@@ -59,6 +91,82 @@ pub trait MonitorBackend {
         Err(BackendError::NotSupported)
     }
 
+    /// Get detailed memory usage for a process.
+    fn process_memory(&self, _pid: u32) -> BackendResult<ProcessMemory> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the memory mappings of a process.
+    fn process_memory_maps(&self, _pid: u32) -> BackendResult<Vec<MemoryMapping>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the OOM killer scores for a process.
+    fn process_oom(&self, _pid: u32) -> BackendResult<ProcessOom> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the context switches per second of a process.
+    fn process_ctx_switches(&self, _pid: u32) -> BackendResult<ContextSwitches> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the fraction of time a process spent waiting for a CPU since the last
+    /// refresh.
+    fn process_sched_wait(&self, _pid: u32) -> BackendResult<f32> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the open files of a process.
+    fn process_open_files(&self, _pid: u32) -> BackendResult<Vec<OpenFile>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the environment variables of a process, sorted by name.
+    fn process_environ(&self, _pid: u32) -> BackendResult<Vec<(String, String)>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get miscellaneous details (working directory, cgroup, etc.) for a process.
+    fn process_details(&self, _pid: u32) -> BackendResult<ProcessDetails> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the file descriptor usage and limits for a process.
+    fn process_fd_usage(&self, _pid: u32) -> BackendResult<ProcessFdUsage> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the I/O scheduling priority of a process.
+    fn process_io_priority(&self, _pid: u32) -> BackendResult<IOPriority> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Get the CPUs a process may run on.
+    fn process_affinity(&self, _pid: u32) -> BackendResult<Vec<u32>> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Send a signal to a process.
+    fn signal_process(&self, _pid: u32, _signal: ProcessSignal) -> BackendResult<()> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Set the nice value of a process.
+    fn set_process_nice(&self, _pid: u32, _nice: i32) -> BackendResult<()> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Set the I/O scheduling priority of a process.
+    fn set_process_io_priority(&self, _pid: u32, _prio: IOPriority) -> BackendResult<()> {
+        Err(BackendError::NotSupported)
+    }
+
+    /// Set the CPUs a process may run on.
+    fn set_process_affinity(&self, _pid: u32, _cpus: &[u32]) -> BackendResult<()> {
+        Err(BackendError::NotSupported)
+    }
+
     /// Get the networks.
     fn networks(&self) -> BackendResult<Vec<NetworkStats>> {
         Err(BackendError::NotSupported)
@@ -1,24 +1,31 @@
 //! System monitoring with [sysinfo].
 
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 
 use itertools::Itertools;
 use log::*;
 use sysinfo::{
     CpuRefreshKind, Disks, MemoryRefreshKind, Networks, Pid, ProcessRefreshKind, RefreshKind,
-    System,
+    Signal, System, ThreadKind,
 };
 
-use crate::model::*;
+use crate::model::{process::ResourceLimit, *};
 
-use super::{error::generic_err, util::RefreshRecord, BackendResult, MonitorBackend};
+use super::{error::generic_err, util::RefreshRecord, BackendError, BackendResult, MonitorBackend};
 
 /// Backend using [sysinfo].
 pub struct SysInfoBackend {
     system: System,
     disks: Disks,
     networks: Networks,
+    /// Network byte counts when each interface was first seen, for cumulative totals.
+    net_baseline: HashMap<String, (u64, u64)>,
+    /// Start times of the processes and threads at the previous refresh, to find
+    /// new and exited ones.
+    previous: HashMap<Pid, u64>,
     clock: RefreshRecord,
+    cpu_mode: CpuPctMode,
 }
 
 impl SysInfoBackend {
@@ -30,18 +37,40 @@ impl SysInfoBackend {
         system.refresh_specifics(RefreshKind::everything());
         disks.refresh(true);
         networks.refresh(true);
-        Ok(SysInfoBackend {
+        let mut backend = SysInfoBackend {
             system,
             disks,
             networks,
+            net_baseline: HashMap::new(),
+            previous: HashMap::new(),
             clock: RefreshRecord::new(),
-        })
+            cpu_mode: CpuPctMode::PerCore,
+        };
+        backend.update_net_baseline();
+        Ok(backend)
+    }
+
+    /// Record the byte counts of interfaces seen for the first time, so
+    /// interfaces that appear later also count from when they were found.
+    fn update_net_baseline(&mut self) {
+        for (name, stats) in &self.networks {
+            if !self.net_baseline.contains_key(name) {
+                let counts = (stats.total_received(), stats.total_transmitted());
+                self.net_baseline.insert(name.clone(), counts);
+            }
+        }
     }
 }
 
 impl MonitorBackend for SysInfoBackend {
     fn update(&mut self, _opts: &Options) -> BackendResult<()> {
         debug!("refreshing system");
+        self.previous = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, p)| (*pid, p.start_time()))
+            .collect();
         let specs = RefreshKind::nothing()
             .with_cpu(CpuRefreshKind::everything())
             .with_memory(MemoryRefreshKind::everything())
@@ -49,10 +78,15 @@ impl MonitorBackend for SysInfoBackend {
         self.system.refresh_specifics(specs);
         self.disks.refresh(true);
         self.networks.refresh(true);
+        self.update_net_baseline();
         self.clock.update();
         Ok(())
     }
 
+    fn set_cpu_mode(&mut self, mode: CpuPctMode) {
+        self.cpu_mode = mode;
+    }
+
     fn hostname(&self) -> BackendResult<String> {
         System::host_name().ok_or(generic_err("no host name"))
     }
@@ -86,6 +120,18 @@ impl MonitorBackend for SysInfoBackend {
         })
     }
 
+    fn cpus(&self) -> BackendResult<Vec<CPU>> {
+        Ok(self
+            .system
+            .cpus()
+            .iter()
+            .map(|cpu| CPU {
+                utilization: cpu.cpu_usage() / 100.0,
+                extended: cpu::ExtendedCPU::None,
+            })
+            .collect())
+    }
+
     fn memory(&self) -> BackendResult<Memory> {
         let used = self.system.used_memory();
         let total = self.system.total_memory();
@@ -117,49 +163,56 @@ impl MonitorBackend for SysInfoBackend {
         })
     }
 
-    fn processes<'a>(&'a self) -> BackendResult<Vec<Process>> {
+    fn processes(&self) -> BackendResult<Vec<Process>> {
         let procs = self.system.processes();
         let mut out = Vec::with_capacity(procs.len());
+        // sysinfo lists threads alongside processes
         for proc in procs.values() {
-            let disk = proc.disk_usage();
-            out.push(Process {
-                pid: proc.pid().as_u32(),
-                ppid: proc.parent().map(|p| p.as_u32()),
-                name: proc.name().to_string_lossy().to_string(),
-                uid: proc.user_id().map(|u| **u),
-                status: match proc.status() {
-                    sysinfo::ProcessStatus::Idle => 'I',
-                    sysinfo::ProcessStatus::Run => 'R',
-                    sysinfo::ProcessStatus::Sleep => 'S',
-                    sysinfo::ProcessStatus::Stop => 'T',
-                    sysinfo::ProcessStatus::Zombie => 'Z',
-                    sysinfo::ProcessStatus::Tracing => 't',
-                    sysinfo::ProcessStatus::Dead => 'X',
-                    sysinfo::ProcessStatus::Wakekill => 'K',
-                    sysinfo::ProcessStatus::Waking => 'W',
-                    sysinfo::ProcessStatus::Parked => 'P',
-                    sysinfo::ProcessStatus::LockBlocked => 'L',
-                    sysinfo::ProcessStatus::UninterruptibleDiskSleep => 'D',
-                    sysinfo::ProcessStatus::Unknown(_) => '?',
-                },
-                cpu_util: proc.cpu_usage() / 100.0,
-                cpu_time: None,
-                cpu_utime: None,
-                cpu_stime: None,
-                mem_util: proc.memory() as f32 / self.memory()?.total as f32,
-                mem_rss: proc.memory(),
-                mem_virt: proc.virtual_memory(),
-                io_read: Some(self.clock.norm_u64(disk.read_bytes)),
-                io_write: Some(self.clock.norm_u64(disk.written_bytes)),
-            })
+            if proc.thread_kind() != Some(ThreadKind::Userland) {
+                out.push(self.process_entry(proc, None)?);
+            }
+        }
+        Ok(out)
+    }
+
+    fn threads(&self) -> BackendResult<Vec<Process>> {
+        let procs = self.system.processes();
+        let owners: HashMap<Pid, Pid> = procs
+            .values()
+            .flat_map(|p| p.tasks().into_iter().flatten().map(|t| (*t, p.pid())))
+            .collect();
+        let mut out = Vec::with_capacity(procs.len());
+        for proc in procs.values() {
+            let tgid = owners.get(&proc.pid()).copied().unwrap_or(proc.pid());
+            out.push(self.process_entry(proc, Some(tgid.as_u32()))?);
         }
         Ok(out)
     }
 
+    fn process_churn(&self) -> BackendResult<ProcessChurn> {
+        if self.previous.is_empty() {
+            return Err(BackendError::NotAvailable);
+        }
+        let procs = self.system.processes();
+        let started = procs
+            .iter()
+            .filter(|(pid, p)| self.previous.get(pid) != Some(&p.start_time()))
+            .count();
+        let exited = self
+            .previous
+            .keys()
+            .filter(|pid| !procs.contains_key(pid))
+            .count();
+        Ok(ProcessChurn {
+            started: self.clock.norm_f32(started as f32),
+            exited: self.clock.norm_f32(exited as f32),
+        })
+    }
+
     fn process_cmd_info(&self, pid: u32) -> BackendResult<ProcessCommandInfo> {
         let procs = self.system.processes();
         let pid = Pid::from_u32(pid);
-        let proc = procs.get(&pid).ok_or(generic_err("missing process"))?;
+        let proc = procs.get(&pid).ok_or(BackendError::NotFound)?;
         Ok(ProcessCommandInfo {
             exe: proc
                 .exe()
@@ -173,16 +226,96 @@ impl MonitorBackend for SysInfoBackend {
         })
     }
 
+    fn process_details(&self, pid: u32) -> BackendResult<ProcessDetails> {
+        let proc = self
+            .system
+            .process(Pid::from_u32(pid))
+            .ok_or(BackendError::NotFound)?;
+        let disk = proc.disk_usage();
+        Ok(ProcessDetails {
+            cwd: proc.cwd().map(|p| p.display().to_string()),
+            gid: proc.group_id().map(|g| *g),
+            cgroup: None,
+            io_read_total: Some(disk.total_read_bytes),
+            io_write_total: Some(disk.total_written_bytes),
+            env_count: Some(proc.environ().len()),
+        })
+    }
+
+    fn process_environ(&self, pid: u32) -> BackendResult<Vec<(String, String)>> {
+        let proc = self
+            .system
+            .process(Pid::from_u32(pid))
+            .ok_or(BackendError::NotFound)?;
+        let mut env: Vec<_> = proc
+            .environ()
+            .iter()
+            .filter_map(|var| {
+                let var = var.to_string_lossy();
+                var.split_once('=')
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+            })
+            .collect();
+        env.sort();
+        Ok(env)
+    }
+
+    fn process_fd_usage(&self, pid: u32) -> BackendResult<ProcessFdUsage> {
+        let procs = self.system.processes();
+        let proc = procs
+            .get(&Pid::from_u32(pid))
+            .ok_or(BackendError::NotFound)?;
+        Ok(ProcessFdUsage {
+            open: proc.open_files().ok_or(BackendError::NotAvailable)? as u64,
+            nofile: ResourceLimit {
+                soft: proc.open_files_limit().map(|l| l as u64),
+                hard: None,
+            },
+            nproc: ResourceLimit::default(),
+        })
+    }
+
+    fn signal_process(&self, pid: u32, signal: ProcessSignal) -> BackendResult<()> {
+        let proc = self
+            .system
+            .process(Pid::from_u32(pid))
+            .ok_or(BackendError::NotFound)?;
+        let signal = match signal {
+            ProcessSignal::Term => Signal::Term,
+            ProcessSignal::Kill => Signal::Kill,
+            ProcessSignal::Int => Signal::Interrupt,
+            ProcessSignal::Hup => Signal::Hangup,
+            ProcessSignal::Stop => Signal::Stop,
+            ProcessSignal::Cont => Signal::Continue,
+            ProcessSignal::Usr1 => Signal::User1,
+            ProcessSignal::Usr2 => Signal::User2,
+        };
+        match proc.kill_with(signal) {
+            Some(true) => Ok(()),
+            Some(false) => Err(generic_err("failed to send signal")),
+            None => Err(BackendError::NotSupported),
+        }
+    }
+
     fn networks(&self) -> BackendResult<Vec<NetworkStats>> {
         Ok(self
             .networks
             .into_iter()
-            .map(|(name, stats)| NetworkStats {
-                name: name.clone(),
-                rx_bytes: self.clock.norm_u64(stats.received()),
-                tx_bytes: self.clock.norm_u64(stats.transmitted()),
-                rx_packets: self.clock.norm_u64(stats.packets_received()),
-                tx_packets: self.clock.norm_u64(stats.packets_transmitted()),
+            .map(|(name, stats)| {
+                let (rx_base, tx_base) = self.net_baseline.get(name).copied().unwrap_or_default();
+                NetworkStats {
+                    name: name.clone(),
+                    rx_bytes: self.clock.norm_u64(stats.received()),
+                    tx_bytes: self.clock.norm_u64(stats.transmitted()),
+                    rx_packets: self.clock.norm_u64(stats.packets_received()),
+                    tx_packets: self.clock.norm_u64(stats.packets_transmitted()),
+                    totals: IOTotals {
+                        rx_boot: stats.total_received(),
+                        tx_boot: stats.total_transmitted(),
+                        rx_start: stats.total_received().saturating_sub(rx_base),
+                        tx_start: stats.total_transmitted().saturating_sub(tx_base),
+                    },
+                }
             })
             .collect())
     }
@@ -205,3 +338,82 @@ impl MonitorBackend for SysInfoBackend {
         false
     }
 }
+
+impl SysInfoBackend {
+    /// Convert a sysinfo process (or thread) into a process entry.
+    fn process_entry(&self, proc: &sysinfo::Process, tgid: Option<u32>) -> BackendResult<Process> {
+        let disk = proc.disk_usage();
+        let pid = proc.pid().as_u32();
+        let parent = proc.parent().map(|p| p.as_u32());
+        let start_time = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(proc.start_time()));
+        // sysinfo reports usage as a percentage of one core
+        let cpu_scale = match self.cpu_mode {
+            CpuPctMode::PerCore => 100.0,
+            CpuPctMode::OfTotal => 100.0 * self.logical_cpu_count()?.max(1) as f32,
+        };
+        // a different start time means the PID has been reused
+        let change = match self.previous.get(&proc.pid()) {
+            _ if self.previous.is_empty() => ProcessChange::Unchanged,
+            Some(start) if *start == proc.start_time() => ProcessChange::Unchanged,
+            _ => ProcessChange::Started,
+        };
+        Ok(Process {
+            pid,
+            ppid: match tgid {
+                // secondary threads are children of their process's main thread
+                Some(tgid) if tgid != proc.pid().as_u32() => Some(tgid),
+                _ => parent,
+            },
+            tgid,
+            name: proc.name().to_string_lossy().to_string(),
+            uid: proc.user_id().map(|u| **u),
+            // sysinfo has no process flags, so look for kthreadd and its children,
+            // or live processes without a command line
+            kernel_thread: pid == 2
+                || parent == Some(2)
+                || (tgid.is_none()
+                    && proc.cmd().is_empty()
+                    && proc.status() != sysinfo::ProcessStatus::Zombie),
+            start_time,
+            change,
+            status: match proc.status() {
+                sysinfo::ProcessStatus::Idle => 'I',
+                sysinfo::ProcessStatus::Run => 'R',
+                sysinfo::ProcessStatus::Sleep => 'S',
+                sysinfo::ProcessStatus::Stop => 'T',
+                sysinfo::ProcessStatus::Zombie => 'Z',
+                sysinfo::ProcessStatus::Tracing => 't',
+                sysinfo::ProcessStatus::Dead => 'X',
+                sysinfo::ProcessStatus::Wakekill => 'K',
+                sysinfo::ProcessStatus::Waking => 'W',
+                sysinfo::ProcessStatus::Parked => 'P',
+                sysinfo::ProcessStatus::LockBlocked => 'L',
+                sysinfo::ProcessStatus::UninterruptibleDiskSleep => 'D',
+                sysinfo::ProcessStatus::Unknown(_) => '?',
+            },
+            cpu_util: proc.cpu_usage() / cpu_scale,
+            cpu_time: None,
+            cpu_utime: None,
+            cpu_stime: None,
+            mem_util: proc.memory() as f32 / self.memory()?.total as f32,
+            mem_rss: proc.memory(),
+            mem_virt: proc.virtual_memory(),
+            mem_detail: None,
+            io_read: Some(self.clock.norm_u64(disk.read_bytes)),
+            io_write: Some(self.clock.norm_u64(disk.written_bytes)),
+            minor_faults: None,
+            major_faults: None,
+            vol_ctx_switches: None,
+            invol_ctx_switches: None,
+            sched_wait: None,
+            // sysinfo's tasks exclude the main thread
+            threads: proc.tasks().map(|t| t.len() as u32 + 1),
+            priority: None,
+            nice: None,
+            policy: None,
+            rt_priority: None,
+            processor: None,
+            tree: None,
+        })
+    }
+}
@@ -2,10 +2,14 @@
 
 use std::{
     cell::RefCell,
+    collections::HashMap,
+    hash::Hash,
     rc::Rc,
     time::{Duration, Instant},
 };
 
+use super::{BackendError, BackendResult};
+
 /// Struct to record time between refreshes.
 pub(super) struct RefreshRecord {
     /// The system tick.
@@ -39,6 +43,11 @@ impl RefreshRecord {
         self.last_tick >= self.tick.current()
     }
 
+    /// Check if it was last updated at the previous tick (or the current one).
+    pub fn is_previous(&self) -> bool {
+        self.last_tick + 1 >= self.tick.current()
+    }
+
     /// Get the last tick value.
     pub fn tick(&self) -> u64 {
         self.last_tick
@@ -121,6 +130,82 @@ impl Tick {
     }
 }
 
+/// Cache for data that is loaded on demand (e.g. for only the visible processes),
+/// and discarded at the next refresh.
+pub(super) struct TickCache<K, V> {
+    window: RefCell<RefreshRecord>,
+    entries: RefCell<HashMap<K, BackendResult<V>>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TickCache<K, V> {
+    pub fn with_tick(tick: &Tick) -> TickCache<K, V> {
+        TickCache {
+            window: RefCell::new(RefreshRecord::with_tick(tick.clone())),
+            entries: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Get the cached value for a key, loading it if it is missing or stale.
+    /// Errors are cached too, so inaccessible data is not retried until the
+    /// next refresh.
+    pub fn get_or_load<F>(&self, key: K, load: F) -> BackendResult<V>
+    where
+        F: FnOnce() -> BackendResult<V>,
+    {
+        let mut window = self.window.borrow_mut();
+        let mut entries = self.entries.borrow_mut();
+        if !window.is_current() {
+            entries.clear();
+            window.update();
+        }
+        entries.entry(key).or_insert_with(load).clone()
+    }
+}
+
+/// Cache for counters that are loaded on demand, like [TickCache], which keeps the
+/// samples from the previous refresh so rates can be computed.
+pub(super) struct RateCache<K, V> {
+    window: RefCell<RefreshRecord>,
+    current: RefCell<HashMap<K, BackendResult<(Instant, V)>>>,
+    previous: RefCell<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> RateCache<K, V> {
+    pub fn with_tick(tick: &Tick) -> RateCache<K, V> {
+        RateCache {
+            window: RefCell::new(RefreshRecord::with_tick(tick.clone())),
+            current: RefCell::new(HashMap::new()),
+            previous: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Compute a rate from the current and previous samples for a key, loading the
+    /// current sample if it is missing or stale.  Keys that were not sampled at the
+    /// previous refresh have no rate until the next one.
+    pub fn rate<T, F, R>(&self, key: K, load: F, rate: R) -> BackendResult<T>
+    where
+        F: FnOnce() -> BackendResult<V>,
+        R: FnOnce(&V, &V, Duration) -> T,
+    {
+        let mut window = self.window.borrow_mut();
+        let mut current = self.current.borrow_mut();
+        let mut previous = self.previous.borrow_mut();
+        if !window.is_current() {
+            *previous = current
+                .drain()
+                .filter_map(|(k, s)| s.ok().map(|s| (k, s)))
+                .collect();
+            window.update();
+        }
+        let (time, cur) = current
+            .entry(key.clone())
+            .or_insert_with(|| load().map(|v| (Instant::now(), v)))
+            .clone()?;
+        let (prev_time, prev) = previous.get(&key).ok_or(BackendError::NotAvailable)?;
+        Ok(rate(&cur, prev, time.duration_since(*prev_time)))
+    }
+}
+
 /// Trait for computing differences between two observations.
 pub(super) trait Diff {
     type Difference;
@@ -1,6 +1,6 @@
 //! Events, states, and controller.
 use anyhow::Result;
-use crossterm::event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers, MouseEventKind};
 use log::*;
 use ratatui::{backend::Backend, Terminal};
 
@@ -35,7 +35,7 @@ fn event_loop_iter<'b, TB: Backend>(
 ) -> Result<Option<Box<dyn StateController>>> {
     term.draw(|frame| controller.render(state, frame).expect("rendering failed"))?;
     clock.update_now();
-    if poll(clock.next_wait())? {
+    while poll(clock.next_wait())? {
         match read()? {
             Event::Key(e) if e.kind == KeyEventKind::Press => {
                 debug!("key event {:?}", e);
@@ -48,9 +48,27 @@ fn event_loop_iter<'b, TB: Backend>(
                     return Ok(controller.handle_key(e.code, state));
                 }
             }
-            _ => (), // covers resize too, no action needed
+            // motion reports arrive for every pointer move; keep waiting
+            // rather than redrawing for each one
+            Event::Mouse(e)
+                if matches!(e.kind, MouseEventKind::Moved | MouseEventKind::Drag(_)) =>
+            {
+                if clock.want_refresh(state.options.refresh) {
+                    break;
+                }
+            }
+            Event::Mouse(e) => {
+                trace!("mouse event {:?}", e);
+                if let Some(next) = controller.handle_mouse(e, state) {
+                    return Ok(Some(next));
+                }
+                break;
+            }
+            _ => break, // covers resize too, no action needed
         }
-    } else if clock.want_refresh(state.options.refresh) {
+    }
+    // check even after an event, so mouse motion cannot hold off refreshes
+    if clock.want_refresh(state.options.refresh) {
         clock.mark_refresh();
         state.refresh()?;
     }
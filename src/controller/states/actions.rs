@@ -0,0 +1,57 @@
+//! Shared logic for actions on processes.
+
+use crate::backend::{BackendError, BackendResult};
+use crate::model::MonitorState;
+
+/// Get the processes an action applies to: the tagged processes if there are
+/// any, and the selected process otherwise.
+pub fn action_targets(state: &MonitorState<'_>) -> Vec<u32> {
+    let mut targets: Vec<u32> = state.tagged.keys().copied().collect();
+    targets.sort();
+    if targets.is_empty() {
+        targets.extend(state.selection.pid);
+    }
+    targets
+}
+
+/// Apply an action to processes and report the outcome in the status line.
+///
+/// `what` describes the action in past tense (e.g. "sent SIGTERM to").  Tags
+/// are cleared if the action succeeds on all processes.
+pub fn apply_to_processes<F>(state: &mut MonitorState<'_>, targets: &[u32], what: &str, action: F)
+where
+    F: Fn(&MonitorState<'_>, u32) -> BackendResult<()>,
+{
+    let mut failures = Vec::new();
+    for pid in targets {
+        if let Err(e) = action(state, *pid) {
+            let reason = match e {
+                BackendError::NotAllowed => "permission denied".to_string(),
+                BackendError::NotFound => "no such process".to_string(),
+                e => e.to_string(),
+            };
+            failures.push(format!("{}: {}", pid, reason));
+        }
+    }
+
+    let done = targets.len() - failures.len();
+    if failures.is_empty() {
+        state.tagged.clear();
+        let procs = match targets {
+            [pid] => format!("process {}", pid),
+            _ => format!("{} processes", done),
+        };
+        state.set_status(format!("{} {}", what, procs), false);
+    } else {
+        state.set_status(
+            format!(
+                "{} {} of {} processes; failed: {}",
+                what,
+                done,
+                targets.len(),
+                failures.join(", ")
+            ),
+            true,
+        );
+    }
+}
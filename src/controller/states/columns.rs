@@ -0,0 +1,57 @@
+use anyhow::Result;
+use crossterm::event::KeyCode;
+
+use crate::model::MonitorState;
+use crate::view::{optional_process_columns, render_column_chooser, render_dashboard};
+
+use super::{DefaultStateController, StateController};
+
+/// State for choosing the optional process table columns.
+pub struct ColumnsStateController {
+    cursor: usize,
+}
+
+impl ColumnsStateController {
+    pub fn new() -> Box<ColumnsStateController> {
+        Box::new(ColumnsStateController { cursor: 0 })
+    }
+}
+
+impl StateController for ColumnsStateController {
+    fn render<'s>(
+        &self,
+        state: &mut MonitorState<'s>,
+        frame: &mut ratatui::Frame<'_>,
+    ) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_column_chooser(frame, state, self.cursor)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        let columns = optional_process_columns();
+        match code {
+            KeyCode::Char('q') | KeyCode::Char('f') | KeyCode::Esc => {
+                return Some(DefaultStateController::new())
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') if self.cursor + 1 < columns.len() => {
+                self.cursor += 1
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                if let Some((label, _)) = columns.get(self.cursor) {
+                    let cols = &mut state.options.proc_columns;
+                    if !cols.remove(*label) {
+                        cols.insert(label.to_string());
+                    }
+                }
+            }
+            _ => (),
+        }
+        Some(self)
+    }
+}
@@ -0,0 +1,87 @@
+//! State controller for the process detail view.
+
+use std::cell::RefCell;
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use ratatui::Frame;
+
+use crate::model::{MonitorState, RunningProcesses};
+use crate::view::render_process_detail;
+
+use super::environ::EnvironStateController;
+use super::scroll::ScrollStateController;
+use super::{DefaultStateController, StateController};
+
+/// Number of samples of CPU and memory history to keep.
+const HISTORY_LEN: usize = 240;
+
+/// Resource usage history of the displayed process.
+#[derive(Default)]
+struct History {
+    /// The state update the last sample was taken from.
+    update: Option<u64>,
+    /// CPU utilization in tenths of a percent.
+    cpu: Vec<u64>,
+    /// Resident set size in bytes.
+    rss: Vec<u64>,
+}
+
+/// State for viewing the details of a single process.
+pub struct DetailStateController {
+    pid: u32,
+    history: RefCell<History>,
+}
+
+impl DetailStateController {
+    pub fn new(pid: u32) -> Box<DetailStateController> {
+        Box::new(DetailStateController {
+            pid,
+            history: RefCell::new(History::default()),
+        })
+    }
+
+    /// Record the process's current usage, if the data has been refreshed.
+    fn sample(&self, state: &MonitorState<'_>) -> Result<()> {
+        let mut hist = self.history.borrow_mut();
+        if hist.update == Some(state.updates) {
+            return Ok(());
+        }
+        hist.update = Some(state.updates);
+
+        let procs = state.processes()?;
+        if let Some(proc) = procs.iter().find(|p| p.pid == self.pid) {
+            hist.cpu.push((proc.cpu_util * 1000.0).round() as u64);
+            hist.rss.push(proc.mem_rss);
+            if hist.cpu.len() > HISTORY_LEN {
+                hist.cpu.remove(0);
+                hist.rss.remove(0);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StateController for DetailStateController {
+    fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
+        self.sample(state)?;
+        let hist = self.history.borrow();
+        render_process_detail(frame, state, self.pid, &hist.cpu, &hist.rss)
+    }
+
+    fn handle_key<'s>(
+        self: Box<Self>,
+        code: KeyCode,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => {
+                Some(DefaultStateController::new())
+            }
+            KeyCode::Char('o') => Some(ScrollStateController::open_files(self.pid, self)),
+            KeyCode::Char('M') => Some(ScrollStateController::memory_maps(self.pid, self)),
+            KeyCode::Char('e') => Some(EnvironStateController::new(self.pid, self)),
+            _ => Some(self),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! State controllers for process environments.
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use ratatui::Frame;
+
+use crate::model::{MonitorState, ScrollPosition};
+use crate::view::{render_dashboard, render_environment, render_prompt};
+
+use super::{scroll_key, DefaultStateController, StateController};
+
+/// State for viewing and searching the environment of a process.
+pub struct EnvironStateController {
+    pid: u32,
+    search: String,
+    /// Whether the search text is being edited.
+    editing: bool,
+    scroll: ScrollPosition,
+    /// The view to return to.
+    parent: Box<dyn StateController>,
+}
+
+impl EnvironStateController {
+    pub fn new(pid: u32, parent: Box<dyn StateController>) -> Box<EnvironStateController> {
+        Box::new(EnvironStateController {
+            pid,
+            search: String::new(),
+            editing: false,
+            scroll: ScrollPosition::default(),
+            parent,
+        })
+    }
+}
+
+impl StateController for EnvironStateController {
+    fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
+        render_environment(
+            frame,
+            state,
+            self.pid,
+            &self.search,
+            self.editing,
+            &self.scroll,
+        )
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        if self.editing {
+            match code {
+                KeyCode::Esc => {
+                    self.search.clear();
+                    self.editing = false;
+                }
+                KeyCode::Enter => self.editing = false,
+                KeyCode::Backspace => {
+                    self.search.pop();
+                }
+                KeyCode::Char(c) => self.search.push(c),
+                _ => (),
+            }
+            self.scroll.home();
+            return Some(self);
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Some(self.parent),
+            KeyCode::Char('/') => self.editing = true,
+            code => scroll_key(&self.scroll, code),
+        }
+        Some(self)
+    }
+
+    fn scroll_position(&self) -> Option<&ScrollPosition> {
+        Some(&self.scroll)
+    }
+}
+
+/// State for choosing the environment variable shown in the process table.
+pub struct EnvColumnStateController {
+    input: String,
+}
+
+impl EnvColumnStateController {
+    pub fn new(state: &MonitorState<'_>) -> Box<EnvColumnStateController> {
+        let input = state.options.env_column.clone().unwrap_or_default();
+        Box::new(EnvColumnStateController { input })
+    }
+}
+
+impl StateController for EnvColumnStateController {
+    fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_prompt(frame, "Environment variable column", &self.input)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Esc => return Some(DefaultStateController::new()),
+            KeyCode::Enter => {
+                let var = self.input.trim();
+                state.options.env_column = if var.is_empty() {
+                    None
+                } else {
+                    Some(var.to_string())
+                };
+                return Some(DefaultStateController::new());
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => (),
+        }
+        Some(self)
+    }
+}
@@ -0,0 +1,63 @@
+//! State controller for the process filter prompt.
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+
+use crate::model::{MonitorState, ProcessFilter};
+use crate::view::{render_dashboard, render_prompt};
+
+use super::{DefaultStateController, StateController};
+
+/// State for entering a process filter, which is applied as it is typed.
+pub struct FilterStateController {
+    input: String,
+}
+
+impl FilterStateController {
+    pub fn new(state: &MonitorState<'_>) -> Box<FilterStateController> {
+        let input = state
+            .options
+            .proc_filter
+            .as_ref()
+            .map(|f| f.pattern().to_string())
+            .unwrap_or_default();
+        Box::new(FilterStateController { input })
+    }
+}
+
+impl StateController for FilterStateController {
+    fn render<'s>(
+        &self,
+        state: &mut MonitorState<'s>,
+        frame: &mut ratatui::Frame<'_>,
+    ) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_prompt(frame, "Filter", &self.input)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Esc => {
+                state.options.proc_filter = None;
+                return Some(DefaultStateController::new());
+            }
+            KeyCode::Enter => return Some(DefaultStateController::new()),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => return Some(self),
+        }
+        state.options.proc_filter = if self.input.is_empty() {
+            None
+        } else {
+            Some(ProcessFilter::new(&self.input))
+        };
+        Some(self)
+    }
+}
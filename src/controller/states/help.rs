@@ -22,7 +22,7 @@ impl StateController for HelpStateController {
         state: &mut MonitorState<'s>,
         frame: &mut ratatui::Frame<'_>,
     ) -> Result<()> {
-        render_dashboard(frame, &state)?;
+        render_dashboard(frame, state)?;
         render_help(frame, state, &self.bindings)?;
         Ok(())
     }
@@ -1,13 +1,22 @@
 //! Actions for different application states / screens.
 
 use anyhow::Result;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, MouseEvent, MouseEventKind};
 use ratatui::Frame;
 
-use crate::model::MonitorState;
+use crate::model::{MonitorState, ScrollPosition};
 
+mod actions;
+pub mod columns;
+pub mod detail;
+pub mod environ;
+pub mod filter;
 pub mod help;
 pub mod monitor;
+pub mod scroll;
+pub mod setting;
+pub mod signal;
+pub mod summary;
 
 /// State-specific controller logic.
 pub trait StateController {
@@ -19,6 +28,48 @@ pub trait StateController {
         code: KeyCode,
         state: &mut MonitorState<'s>,
     ) -> Option<Box<dyn StateController>>;
+    /// Handle a mouse event, returning a state controller to switch to (if any).
+    ///
+    /// By default, the mouse wheel scrolls the view's scroll position.
+    fn handle_mouse<'s>(
+        &self,
+        event: MouseEvent,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        if let Some(scroll) = self.scroll_position() {
+            scroll_mouse(scroll, event);
+        }
+        None
+    }
+    /// The scroll position of a scrollable view.
+    fn scroll_position(&self) -> Option<&ScrollPosition> {
+        None
+    }
 }
 
 pub use monitor::DefaultStateController;
+
+/// Handle a key that scrolls a list view.
+fn scroll_key(scroll: &ScrollPosition, code: KeyCode) {
+    match code {
+        KeyCode::Up => scroll.scroll_by(-1),
+        KeyCode::Down => scroll.scroll_by(1),
+        KeyCode::PageUp => scroll.scroll_pages(-1),
+        KeyCode::PageDown => scroll.scroll_pages(1),
+        KeyCode::Home => scroll.home(),
+        KeyCode::End => scroll.end(),
+        _ => (),
+    }
+}
+
+/// Lines scrolled by one step of the mouse wheel.
+const WHEEL_LINES: isize = 3;
+
+/// Handle a mouse event that scrolls a list view.
+fn scroll_mouse(scroll: &ScrollPosition, event: MouseEvent) {
+    match event.kind {
+        MouseEventKind::ScrollUp => scroll.scroll_by(-WHEEL_LINES),
+        MouseEventKind::ScrollDown => scroll.scroll_by(WHEEL_LINES),
+        _ => (),
+    }
+}
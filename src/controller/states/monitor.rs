@@ -1,27 +1,104 @@
 //! State controller for the default monitor mode.
 
+use std::cell::RefCell;
+
 use anyhow::Result;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use log::*;
+use ratatui::layout::Position;
 use ratatui::Frame;
 
 use crate::controller::commands::{dispatch_key, kc, kc_nop, CommandAction};
-use crate::model::MonitorState;
-use crate::view::render_dashboard;
+use crate::model::tree::ProcessTreeNode;
+use crate::model::{
+    MonitorState, ProcSortOrder, Process, ProcessChange, RunningProcesses, ThreadMode,
+};
+use crate::view::{render_dashboard, sort_columns, DashboardLayout};
 
+use super::actions::action_targets;
+use super::columns::ColumnsStateController;
+use super::detail::DetailStateController;
+use super::environ::{EnvColumnStateController, EnvironStateController};
+use super::filter::FilterStateController;
 use super::help::HelpStateController;
-use super::StateController;
+use super::scroll::ScrollStateController;
+use super::setting::{Setting, SettingStateController};
+use super::signal::SignalStateController;
+use super::summary::SummaryStateController;
+use super::{StateController, WHEEL_LINES};
 
-pub struct DefaultStateController {}
+pub struct DefaultStateController {
+    /// Layout of the last rendered dashboard, for locating mouse events.
+    layout: RefCell<DashboardLayout>,
+}
 
 impl DefaultStateController {
     pub fn new() -> Box<DefaultStateController> {
-        Box::new(DefaultStateController {})
+        Box::new(DefaultStateController {
+            layout: RefCell::new(DashboardLayout::default()),
+        })
+    }
+
+    /// Open a view of the selected process, with this state as its parent.
+    fn open_selected<C: StateController + 'static>(
+        self: Box<Self>,
+        state: &mut MonitorState<'_>,
+        open: impl FnOnce(u32, Box<dyn StateController>) -> Box<C>,
+    ) -> Option<Box<dyn StateController>> {
+        match state.selection.pid {
+            Some(pid) => Some(open(pid, self)),
+            None => {
+                state.set_status("no process selected", true);
+                Some(self)
+            }
+        }
     }
 }
 
 impl StateController for DefaultStateController {
     fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
-        render_dashboard(frame, &state)
+        *self.layout.borrow_mut() = render_dashboard(frame, state)?;
+        Ok(())
+    }
+
+    fn handle_mouse<'s>(
+        &self,
+        event: MouseEvent,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        let layout = self.layout.borrow();
+        let pos = Position::new(event.column, event.row);
+        let wheel = match event.kind {
+            MouseEventKind::ScrollUp => -WHEEL_LINES,
+            MouseEventKind::ScrollDown => WHEEL_LINES,
+            MouseEventKind::Down(MouseButton::Left) => 0,
+            _ => return None,
+        };
+
+        if wheel != 0 {
+            if layout.in_tables(pos) {
+                state.table_scroll.scroll_by(wheel);
+            } else if layout.proc_row_at(pos).is_some() {
+                let procs = displayed_processes(state);
+                state.selection.move_by(&procs, wheel);
+            }
+            None
+        } else if let Some(summary) = layout.summary_at(pos) {
+            Some(SummaryStateController::new(summary))
+        } else if let Some(order) = layout.proc_header_at(pos) {
+            if let Some(order) = order {
+                sort_by_column(state, order);
+            }
+            None
+        } else if let Some(row) = layout.proc_row_at(pos) {
+            let procs = displayed_processes(state);
+            state
+                .selection
+                .select(&procs, state.selection.row_index(row));
+            None
+        } else {
+            None
+        }
     }
 
     fn handle_key<'s>(
@@ -36,6 +113,34 @@ impl StateController for DefaultStateController {
                 let bindings = KEY_BINDINGS.iter().map(|(c, d, _)| (*c, *d)).collect();
                 Some(HelpStateController::new(bindings))
             }
+            'f' => Some(ColumnsStateController::new()),
+            '/' => Some(FilterStateController::new(state)),
+            'E' => Some(EnvColumnStateController::new(state)),
+            'D' => self.open_selected(state, |pid, _| DetailStateController::new(pid)),
+            'o' => self.open_selected(state, ScrollStateController::open_files),
+            'M' => self.open_selected(state, ScrollStateController::memory_maps),
+            'e' => self.open_selected(state, EnvironStateController::new),
+            's' | 'n' | 'I' | 'p' => {
+                let targets = action_targets(state);
+                if targets.is_empty() {
+                    state.set_status("no process selected", true);
+                    return Some(self);
+                }
+                match c {
+                    's' => Some(SignalStateController::new(targets)),
+                    'n' => Some(SettingStateController::new(state, Setting::Nice, targets)),
+                    'I' => Some(SettingStateController::new(
+                        state,
+                        Setting::IOPriority,
+                        targets,
+                    )),
+                    _ => Some(SettingStateController::new(
+                        state,
+                        Setting::Affinity,
+                        targets,
+                    )),
+                }
+            }
             _ => Some(self),
         }
     }
@@ -49,6 +154,20 @@ fn kc_help(_state: &mut MonitorState<'_>) -> char {
     'h'
 }
 
+fn kc_columns(_state: &mut MonitorState<'_>) -> char {
+    'f'
+}
+
+fn kc_filter(_state: &mut MonitorState<'_>) -> char {
+    '/'
+}
+
+fn kc_clear_filter(state: &mut MonitorState<'_>) -> char {
+    state.options.proc_filter = None;
+    state.options.proc_user = None;
+    '_'
+}
+
 fn kc_sort_auto(state: &mut MonitorState<'_>) -> char {
     state.proc_sort = None;
     '_'
@@ -74,15 +193,403 @@ fn kc_sort_time(state: &mut MonitorState<'_>) -> char {
     '_'
 }
 
+fn kc_sort_pss(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::PSS);
+    '_'
+}
+
+fn kc_sort_faults(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::Faults);
+    '_'
+}
+
+fn kc_sort_ctx_switches(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::CtxSwitches);
+    '_'
+}
+
+fn kc_sort_sched_wait(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::SchedWait);
+    '_'
+}
+
+fn kc_sort_threads(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::Threads);
+    '_'
+}
+
+fn kc_sort_nice(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::Nice);
+    '_'
+}
+
+fn kc_sort_age(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(crate::model::ProcSortOrder::Age);
+    '_'
+}
+
+fn kc_sort_name(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort = Some(ProcSortOrder::Name);
+    '_'
+}
+
+fn kc_sort_reverse(state: &mut MonitorState<'_>) -> char {
+    state.proc_sort_reverse = !state.proc_sort_reverse;
+    '_'
+}
+
+fn kc_sort_prev_column(state: &mut MonitorState<'_>) -> char {
+    cycle_sort_column(state, -1);
+    '_'
+}
+
+fn kc_sort_next_column(state: &mut MonitorState<'_>) -> char {
+    cycle_sort_column(state, 1);
+    '_'
+}
+
+/// Sort by a column, reversing the order if it is already the sort column.
+fn sort_by_column(state: &mut MonitorState<'_>, order: ProcSortOrder) {
+    let current = state.processes().map(|p| p.active_sort_order());
+    if current.is_ok_and(|o| o == order) {
+        state.proc_sort_reverse = !state.proc_sort_reverse;
+    }
+    state.proc_sort = Some(order);
+}
+
+/// Sort by the displayed column before or after the current sort column.
+fn cycle_sort_column(state: &mut MonitorState<'_>, delta: isize) {
+    let columns = sort_columns(state);
+    if columns.is_empty() {
+        return;
+    }
+    let current = match state.processes() {
+        Ok(procs) => procs.active_sort_order(),
+        Err(e) => {
+            warn!("cannot list processes: {}", e);
+            return;
+        }
+    };
+    let next = match columns.iter().position(|o| *o == current) {
+        Some(i) => (i as isize + delta).rem_euclid(columns.len() as isize) as usize,
+        // the current order has no displayed column, so start at an end
+        None if delta > 0 => 0,
+        None => columns.len() - 1,
+    };
+    state.proc_sort = Some(columns[next]);
+}
+
+fn kc_io_counters(state: &mut MonitorState<'_>) -> char {
+    state.options.io_counters = state.options.io_counters.next();
+    '_'
+}
+
+fn kc_io_totals(state: &mut MonitorState<'_>) -> char {
+    state.options.io_totals = !state.options.io_totals;
+    '_'
+}
+
+fn kc_threads(state: &mut MonitorState<'_>) -> char {
+    state.options.threads = match state.options.threads {
+        ThreadMode::Processes => ThreadMode::AllThreads,
+        _ => ThreadMode::Processes,
+    };
+    '_'
+}
+
+fn kc_tree(state: &mut MonitorState<'_>) -> char {
+    state.options.proc_tree = !state.options.proc_tree;
+    '_'
+}
+
+fn kc_tree_totals(state: &mut MonitorState<'_>) -> char {
+    state.options.tree_totals = !state.options.tree_totals;
+    '_'
+}
+
+fn kc_cpu_mode(state: &mut MonitorState<'_>) -> char {
+    state.set_cpu_mode(state.options.cpu_mode.next());
+    '_'
+}
+
+fn kc_full_commands(state: &mut MonitorState<'_>) -> char {
+    state.options.full_commands = !state.options.full_commands;
+    '_'
+}
+
+fn kc_hide_kernel(state: &mut MonitorState<'_>) -> char {
+    state.options.hide_kernel = !state.options.hide_kernel;
+    '_'
+}
+
+fn kc_hide_idle(state: &mut MonitorState<'_>) -> char {
+    state.options.hide_idle = !state.options.hide_idle;
+    '_'
+}
+
+fn kc_tree_collapse(state: &mut MonitorState<'_>) -> char {
+    // collapse the deepest level of expanded subtrees
+    let nodes = tree_nodes(state);
+    let expanded = nodes.iter().filter(|(_, n)| n.children > 0 && !n.collapsed);
+    if let Some(depth) = expanded.clone().map(|(_, n)| n.depth).max() {
+        let pids = expanded.filter(|(_, n)| n.depth == depth).map(|(p, _)| *p);
+        state.proc_collapsed.extend(pids);
+    }
+    '_'
+}
+
+fn kc_tree_expand(state: &mut MonitorState<'_>) -> char {
+    // expand the shallowest level of collapsed subtrees
+    let nodes = tree_nodes(state);
+    let collapsed = nodes.iter().filter(|(_, n)| n.collapsed);
+    if let Some(depth) = collapsed.clone().map(|(_, n)| n.depth).min() {
+        for (pid, _) in collapsed.filter(|(_, n)| n.depth == depth) {
+            state.proc_collapsed.remove(pid);
+        }
+    }
+    '_'
+}
+
+fn kc_tree_expand_all(state: &mut MonitorState<'_>) -> char {
+    state.proc_collapsed.clear();
+    '_'
+}
+
+/// Get the tree nodes of the currently-displayed processes.
+fn tree_nodes(state: &MonitorState<'_>) -> Vec<(u32, ProcessTreeNode)> {
+    if !state.options.proc_tree {
+        return Vec::new();
+    }
+    let procs = displayed_processes(state);
+    procs
+        .iter()
+        .filter_map(|p| p.tree.clone().map(|t| (p.pid, t)))
+        .collect()
+}
+
+/// Get the processes in the order they are displayed.
+fn displayed_processes(state: &MonitorState<'_>) -> Vec<Process> {
+    match state.processes() {
+        Ok(mut procs) => {
+            procs.sort();
+            procs.to_vec()
+        }
+        Err(e) => {
+            warn!("cannot list processes: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+fn kc_tag(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    let selected = state
+        .selection
+        .pid
+        .and_then(|pid| procs.iter().find(|p| p.pid == pid));
+    if let Some(proc) = selected {
+        if state.is_tagged(proc) {
+            state.tagged.remove(&proc.pid);
+        } else if proc.change != ProcessChange::Exited {
+            state.tagged.insert(proc.pid, proc.start_time);
+        }
+        state.selection.move_by(&procs, 1);
+    }
+    '_'
+}
+
+fn kc_pin(state: &mut MonitorState<'_>) -> char {
+    let pid = match state.selection.pid {
+        Some(pid) => pid,
+        None => {
+            state.set_status("no process selected", true);
+            return '_';
+        }
+    };
+    let procs = match state.processes() {
+        Ok(procs) => procs,
+        Err(e) => {
+            state.set_status(format!("cannot pin process {}: {}", pid, e), true);
+            return '_';
+        }
+    };
+    let start = procs
+        .iter()
+        .find(|p| p.pid == pid)
+        .and_then(|p| p.start_time);
+    let pinned = procs.is_pinned(pid);
+    // a pin left by an exited process with the same PID is replaced
+    if pinned && state.options.pin_pids.remove(&pid).is_some() {
+        state.set_status(format!("unpinned process {}", pid), false);
+    } else if pinned {
+        state.set_status(format!("process {} is pinned by a pattern", pid), true);
+    } else {
+        state.options.pin_pids.insert(pid, start);
+        state.set_status(format!("pinned process {}", pid), false);
+    }
+    '_'
+}
+
+fn kc_tag_all(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.tagged.extend(
+        procs
+            .iter()
+            .filter(|p| p.change != ProcessChange::Exited)
+            .map(|p| (p.pid, p.start_time)),
+    );
+    '_'
+}
+
+fn kc_untag_all(state: &mut MonitorState<'_>) -> char {
+    state.tagged.clear();
+    '_'
+}
+
+fn kc_detail(_state: &mut MonitorState<'_>) -> char {
+    'D'
+}
+
+fn kc_open_files(_state: &mut MonitorState<'_>) -> char {
+    'o'
+}
+
+fn kc_memory_maps(_state: &mut MonitorState<'_>) -> char {
+    'M'
+}
+
+fn kc_environ(_state: &mut MonitorState<'_>) -> char {
+    'e'
+}
+
+fn kc_env_column(_state: &mut MonitorState<'_>) -> char {
+    'E'
+}
+
+fn kc_signal(_state: &mut MonitorState<'_>) -> char {
+    's'
+}
+
+fn kc_renice(_state: &mut MonitorState<'_>) -> char {
+    'n'
+}
+
+fn kc_ionice(_state: &mut MonitorState<'_>) -> char {
+    'I'
+}
+
+fn kc_affinity(_state: &mut MonitorState<'_>) -> char {
+    'p'
+}
+
+fn kc_select_up(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.move_by(&procs, -1);
+    '_'
+}
+
+fn kc_select_down(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.move_by(&procs, 1);
+    '_'
+}
+
+fn kc_select_page_up(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.move_pages(&procs, -1);
+    '_'
+}
+
+fn kc_select_page_down(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.move_pages(&procs, 1);
+    '_'
+}
+
+fn kc_select_first(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.select(&procs, 0);
+    '_'
+}
+
+fn kc_select_last(state: &mut MonitorState<'_>) -> char {
+    let procs = displayed_processes(state);
+    state.selection.select(&procs, usize::MAX);
+    '_'
+}
+
 static KEY_BINDINGS: &[(KeyCode, &str, CommandAction<char>)] = &[
     (KeyCode::Null, "Application commands", kc_nop),
     (kc('q'), "quit", kc_quit),
     (kc('h'), "help", kc_help),
     (kc('?'), "!help", kc_help),
+    (KeyCode::Null, "Process selection", kc_nop),
+    (KeyCode::Up, "select previous process", kc_select_up),
+    (KeyCode::Down, "select next process", kc_select_down),
+    (kc('k'), "!select previous process", kc_select_up),
+    (kc('j'), "!select next process", kc_select_down),
+    (KeyCode::PageUp, "select up one page", kc_select_page_up),
+    (
+        KeyCode::PageDown,
+        "select down one page",
+        kc_select_page_down,
+    ),
+    (KeyCode::Home, "select first process", kc_select_first),
+    (KeyCode::End, "select last process", kc_select_last),
+    (KeyCode::Null, "Process actions", kc_nop),
+    (KeyCode::Enter, "show process details", kc_detail),
+    (kc('o'), "show open files", kc_open_files),
+    (kc('M'), "show memory maps", kc_memory_maps),
+    (kc('e'), "show environment", kc_environ),
+    (kc(' '), "tag / untag selected process", kc_tag),
+    (kc('G'), "tag all listed processes", kc_tag_all),
+    (kc('b'), "pin / unpin selected process", kc_pin),
+    (kc('g'), "clear tags", kc_untag_all),
+    (kc('s'), "send signal to tagged or selected", kc_signal),
+    (kc('n'), "set nice value", kc_renice),
+    (kc('I'), "set I/O priority", kc_ionice),
+    (kc('p'), "set CPU affinity", kc_affinity),
+    (KeyCode::Null, "Process list filtering", kc_nop),
+    (kc('/'), "filter processes", kc_filter),
+    (KeyCode::Esc, "clear process filters", kc_clear_filter),
     (KeyCode::Null, "Process list sorting", kc_nop),
     (kc('a'), "sort automatically", kc_sort_auto),
     (kc('c'), "sort by CPU", kc_sort_cpu),
     (kc('m'), "sort by memory", kc_sort_memory),
     (kc('i'), "sort by IO", kc_sort_io),
     (kc('t'), "sort by time", kc_sort_time),
+    (kc('P'), "sort by PSS", kc_sort_pss),
+    (kc('F'), "sort by page faults", kc_sort_faults),
+    (kc('X'), "sort by context switches", kc_sort_ctx_switches),
+    (kc('W'), "sort by run queue wait", kc_sort_sched_wait),
+    (kc('H'), "sort by thread count", kc_sort_threads),
+    (kc('N'), "sort by nice", kc_sort_nice),
+    (kc('A'), "sort by age (newest first)", kc_sort_age),
+    (kc('O'), "sort by process name", kc_sort_name),
+    (
+        KeyCode::Left,
+        "sort by previous column",
+        kc_sort_prev_column,
+    ),
+    (KeyCode::Right, "sort by next column", kc_sort_next_column),
+    (kc('R'), "reverse sort order", kc_sort_reverse),
+    (KeyCode::Null, "Display options", kc_nop),
+    (kc('f'), "choose process columns", kc_columns),
+    (kc('E'), "show environment variable column", kc_env_column),
+    (kc('v'), "toggle process tree", kc_tree),
+    (kc('L'), "toggle listing threads", kc_threads),
+    (
+        kc('w'),
+        "toggle full / compact command lines",
+        kc_full_commands,
+    ),
+    (kc('%'), "toggle CPU% per core / of total", kc_cpu_mode),
+    (kc('K'), "hide / show kernel threads", kc_hide_kernel),
+    (kc('Z'), "hide / show idle processes", kc_hide_idle),
+    (kc('-'), "collapse process tree level", kc_tree_collapse),
+    (kc('+'), "expand process tree level", kc_tree_expand),
+    (kc('*'), "expand entire process tree", kc_tree_expand_all),
+    (kc('C'), "show subtree CPU / memory totals", kc_tree_totals),
+    (kc('U'), "cycle I/O rates / totals", kc_io_counters),
+    (kc('T'), "show I/O total rows", kc_io_totals),
 ];
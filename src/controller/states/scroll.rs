@@ -0,0 +1,71 @@
+//! State controller for scrollable process views.
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use ratatui::Frame;
+
+use crate::model::{MonitorState, ScrollPosition};
+use crate::view::{render_memory_maps, render_open_files};
+
+use super::{scroll_key, StateController};
+
+/// Renders a scrollable view of a process.
+type RenderFn = fn(&mut Frame, &MonitorState<'_>, u32, &ScrollPosition) -> Result<()>;
+
+/// State for a read-only view of a process that can be scrolled.
+pub struct ScrollStateController {
+    pid: u32,
+    render: RenderFn,
+    scroll: ScrollPosition,
+    /// The view to return to.
+    parent: Box<dyn StateController>,
+}
+
+impl ScrollStateController {
+    pub fn new(
+        pid: u32,
+        render: RenderFn,
+        parent: Box<dyn StateController>,
+    ) -> Box<ScrollStateController> {
+        Box::new(ScrollStateController {
+            pid,
+            render,
+            scroll: ScrollPosition::default(),
+            parent,
+        })
+    }
+
+    /// View the open files of a process.
+    pub fn open_files(pid: u32, parent: Box<dyn StateController>) -> Box<ScrollStateController> {
+        Self::new(pid, render_open_files, parent)
+    }
+
+    /// View the memory mappings of a process.
+    pub fn memory_maps(pid: u32, parent: Box<dyn StateController>) -> Box<ScrollStateController> {
+        Self::new(pid, render_memory_maps, parent)
+    }
+}
+
+impl StateController for ScrollStateController {
+    fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
+        (self.render)(frame, state, self.pid, &self.scroll)
+    }
+
+    fn handle_key<'s>(
+        self: Box<Self>,
+        code: KeyCode,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => Some(self.parent),
+            code => {
+                scroll_key(&self.scroll, code);
+                Some(self)
+            }
+        }
+    }
+
+    fn scroll_position(&self) -> Option<&ScrollPosition> {
+        Some(&self.scroll)
+    }
+}
@@ -0,0 +1,138 @@
+//! State controller for changing process scheduling settings.
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+
+use crate::model::process::{format_cpu_list, parse_cpu_list};
+use crate::model::{IOPriority, MonitorState, RunningProcesses};
+use crate::view::{render_dashboard, render_prompt};
+
+use super::actions::apply_to_processes;
+use super::{DefaultStateController, StateController};
+
+/// Process settings that can be changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Nice,
+    IOPriority,
+    Affinity,
+}
+
+/// State for entering a new scheduling setting for the selected or tagged processes.
+pub struct SettingStateController {
+    setting: Setting,
+    targets: Vec<u32>,
+    label: String,
+    input: String,
+}
+
+impl SettingStateController {
+    pub fn new(
+        state: &MonitorState<'_>,
+        setting: Setting,
+        targets: Vec<u32>,
+    ) -> Box<SettingStateController> {
+        let what = match setting {
+            Setting::Nice => "Nice value (-20 to 19)",
+            Setting::IOPriority => "I/O priority (rt/N, be/N, idle, none)",
+            Setting::Affinity => "CPU affinity (e.g. 0-3,6)",
+        };
+        let label = match targets.as_slice() {
+            [pid] => format!("{} for {}", what, pid),
+            _ => format!("{} for {} processes", what, targets.len()),
+        };
+        // start with the first process's current value to edit
+        let input = targets
+            .first()
+            .and_then(|pid| current_value(state, setting, *pid))
+            .unwrap_or_default();
+        Box::new(SettingStateController {
+            setting,
+            targets,
+            label,
+            input,
+        })
+    }
+
+    /// Apply the entered setting, reporting errors in the status line.
+    fn apply(&self, state: &mut MonitorState<'_>) {
+        let input = self.input.trim();
+        match self.setting {
+            Setting::Nice => match input.parse::<i32>() {
+                Ok(nice) if (-20..=19).contains(&nice) => {
+                    let what = format!("set nice {} on", nice);
+                    apply_to_processes(state, &self.targets, &what, |state, pid| {
+                        state.set_process_nice(pid, nice)
+                    });
+                }
+                _ => state.set_status(format!("invalid nice value: {}", input), true),
+            },
+            Setting::IOPriority => match IOPriority::parse(input) {
+                Some(prio) => {
+                    let what = format!("set I/O priority {} on", prio.label());
+                    apply_to_processes(state, &self.targets, &what, |state, pid| {
+                        state.set_process_io_priority(pid, prio)
+                    });
+                }
+                None => state.set_status(format!("invalid I/O priority: {}", input), true),
+            },
+            Setting::Affinity => match parse_cpu_list(input) {
+                Some(cpus) => {
+                    let what = format!("set CPU affinity {} on", format_cpu_list(&cpus));
+                    apply_to_processes(state, &self.targets, &what, |state, pid| {
+                        state.set_process_affinity(pid, &cpus)
+                    });
+                }
+                None => state.set_status(format!("invalid CPU list: {}", input), true),
+            },
+        }
+    }
+}
+
+/// Get the current value of a setting for a process, formatted for editing.
+fn current_value(state: &MonitorState<'_>, setting: Setting, pid: u32) -> Option<String> {
+    match setting {
+        Setting::Nice => {
+            let procs = state.processes().ok()?;
+            let proc = procs.iter().find(|p| p.pid == pid)?;
+            proc.nice.map(|n| n.to_string())
+        }
+        Setting::IOPriority => state.process_io_priority(pid).ok().map(|p| p.label()),
+        Setting::Affinity => state
+            .process_affinity(pid)
+            .ok()
+            .map(|cpus| format_cpu_list(&cpus)),
+    }
+}
+
+impl StateController for SettingStateController {
+    fn render<'s>(
+        &self,
+        state: &mut MonitorState<'s>,
+        frame: &mut ratatui::Frame<'_>,
+    ) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_prompt(frame, &self.label, &self.input)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Esc => return Some(DefaultStateController::new()),
+            KeyCode::Enter => {
+                self.apply(state);
+                return Some(DefaultStateController::new());
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => (),
+        }
+        Some(self)
+    }
+}
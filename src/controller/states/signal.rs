@@ -0,0 +1,62 @@
+//! State controller for sending signals to processes.
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+
+use crate::model::{MonitorState, ProcessSignal, RunningProcesses};
+use crate::view::{render_dashboard, render_signal_dialog};
+
+use super::actions::apply_to_processes;
+use super::{DefaultStateController, StateController};
+
+/// State for choosing a signal to send to the selected or tagged processes.
+pub struct SignalStateController {
+    targets: Vec<u32>,
+    cursor: usize,
+}
+
+impl SignalStateController {
+    pub fn new(targets: Vec<u32>) -> Box<SignalStateController> {
+        Box::new(SignalStateController { targets, cursor: 0 })
+    }
+}
+
+impl StateController for SignalStateController {
+    fn render<'s>(
+        &self,
+        state: &mut MonitorState<'s>,
+        frame: &mut ratatui::Frame<'_>,
+    ) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_signal_dialog(frame, &self.targets, self.cursor)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        mut self: Box<Self>,
+        code: KeyCode,
+        state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Some(DefaultStateController::new()),
+            KeyCode::Up | KeyCode::Char('k') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') if self.cursor + 1 < ProcessSignal::ALL.len() => {
+                self.cursor += 1
+            }
+            KeyCode::Enter => {
+                send_signals(state, &self.targets, ProcessSignal::ALL[self.cursor]);
+                return Some(DefaultStateController::new());
+            }
+            _ => (),
+        }
+        Some(self)
+    }
+}
+
+/// Send a signal to processes and report the outcome in the status line.
+fn send_signals(state: &mut MonitorState<'_>, targets: &[u32], signal: ProcessSignal) {
+    let what = format!("sent SIG{} to", signal.name());
+    apply_to_processes(state, targets, &what, |state, pid| {
+        state.signal_process(pid, signal)
+    });
+}
@@ -0,0 +1,54 @@
+//! State controller for summary details.
+
+use anyhow::Result;
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+
+use crate::model::MonitorState;
+use crate::view::{render_dashboard, render_summary_detail, Summary};
+
+use super::{DefaultStateController, StateController};
+
+/// State for showing a summary box in full.
+pub struct SummaryStateController {
+    summary: Summary,
+}
+
+impl SummaryStateController {
+    pub fn new(summary: Summary) -> Box<SummaryStateController> {
+        Box::new(SummaryStateController { summary })
+    }
+}
+
+impl StateController for SummaryStateController {
+    fn render<'s>(&self, state: &mut MonitorState<'s>, frame: &mut Frame<'_>) -> Result<()> {
+        render_dashboard(frame, state)?;
+        render_summary_detail(frame, state, self.summary)?;
+        Ok(())
+    }
+
+    fn handle_key<'s>(
+        self: Box<Self>,
+        code: KeyCode,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => {
+                Some(DefaultStateController::new())
+            }
+            _ => Some(self),
+        }
+    }
+
+    fn handle_mouse<'s>(
+        &self,
+        event: MouseEvent,
+        _state: &mut MonitorState<'s>,
+    ) -> Option<Box<dyn StateController>> {
+        // any click dismisses the popup
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => Some(DefaultStateController::new()),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use std::{path::PathBuf, time::Duration};
+#![allow(clippy::upper_case_acronyms)]
 
-use anyhow::Result;
-use clap::Parser;
+use std::{collections::HashSet, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, ValueEnum};
 use log::*;
 
 mod backend;
@@ -19,7 +21,7 @@ use backend::sysinfo::SysInfoBackend;
 use backend::MonitorBackend;
 use controller::run_event_loop;
 use dump::DumpOpts;
-use model::{MonitorState, Options};
+use model::{CpuPctMode, MonitorState, Options, ProcessFilter, ThreadMode};
 use term::with_terminal;
 
 /// System process monitor.
@@ -40,6 +42,62 @@ struct CLIOptions {
     #[arg(short = 'r', long = "refresh", default_value = "3")]
     refresh: f32,
 
+    /// Show optional process table columns (comma-separated, e.g. "pss,swap").
+    #[arg(long = "columns", value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// Highlight processes using this fraction of their open file limit (e.g. 0.8).
+    #[arg(long = "fd-warn", value_name = "FRAC", default_value = "0")]
+    fd_warn: f32,
+
+    /// Display the process table as a tree.
+    #[arg(long = "tree")]
+    tree: bool,
+
+    /// Filter processes by command line, name, user or PID (regex or substring).
+    #[arg(long = "filter", value_name = "PATTERN")]
+    filter: Option<String>,
+
+    /// Only show processes owned by a user (name or UID).
+    #[arg(long = "user")]
+    user: Option<String>,
+
+    /// Pin processes to the top of the table, by PID or command pattern (repeatable).
+    #[arg(long = "pin", value_name = "PID|PATTERN")]
+    pin: Vec<String>,
+
+    /// List the threads of all processes.
+    #[arg(long = "threads")]
+    threads: bool,
+
+    /// List the threads of a single process.
+    #[arg(long = "threads-of", value_name = "PID", conflicts_with = "threads")]
+    threads_of: Option<u32>,
+
+    /// Scale of process CPU percentages: of one core (can exceed 100%), or of all CPUs.
+    #[arg(long = "cpu-mode", value_enum, default_value = "per-core")]
+    cpu_mode: CpuModeArg,
+
+    /// Show full command lines instead of compact ones.
+    #[arg(long = "full-commands")]
+    full_commands: bool,
+
+    /// Hide kernel threads from the process table.
+    #[arg(long = "hide-kernel")]
+    hide_kernel: bool,
+
+    /// Hide processes with no CPU or I/O activity in the last refresh.
+    #[arg(long = "hide-idle")]
+    hide_idle: bool,
+
+    /// Show the value of an environment variable in a process table column.
+    #[arg(long = "env-column", value_name = "VAR")]
+    env_column: Option<String>,
+
+    /// Leave the mouse to the terminal (no clicking or wheel scrolling).
+    #[arg(long = "no-mouse")]
+    no_mouse: bool,
+
     /// Use fallback sysinfo backend.
     #[arg(long = "sysinfo")]
     sysinfo: bool,
@@ -48,12 +106,50 @@ struct CLIOptions {
     dump: DumpOpts,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum CpuModeArg {
+    PerCore,
+    OfTotal,
+}
+
 fn main() -> Result<()> {
     let cli = CLIOptions::parse();
     init_logging(&cli)?;
 
-    let mut options = Options::default();
-    options.refresh = Duration::from_secs_f32(cli.refresh);
+    let options = Options {
+        refresh: Duration::from_secs_f32(cli.refresh),
+        proc_columns: process_columns(&cli)?,
+        fd_warn_frac: cli.fd_warn,
+        proc_filter: cli.filter.as_deref().map(ProcessFilter::new),
+        proc_user: cli.user.clone(),
+        pin_pids: cli
+            .pin
+            .iter()
+            .filter_map(|p| p.parse().ok())
+            .map(|pid| (pid, None))
+            .collect(),
+        pin_patterns: cli
+            .pin
+            .iter()
+            .filter(|p| p.parse::<u32>().is_err())
+            .map(|p| ProcessFilter::new(p))
+            .collect(),
+        threads: match (cli.threads, cli.threads_of) {
+            (_, Some(pid)) => ThreadMode::ProcessThreads(pid),
+            (true, None) => ThreadMode::AllThreads,
+            (false, None) => ThreadMode::Processes,
+        },
+        proc_tree: cli.tree,
+        cpu_mode: match cli.cpu_mode {
+            CpuModeArg::PerCore => CpuPctMode::PerCore,
+            CpuModeArg::OfTotal => CpuPctMode::OfTotal,
+        },
+        full_commands: cli.full_commands,
+        hide_kernel: cli.hide_kernel,
+        hide_idle: cli.hide_idle,
+        env_column: cli.env_column.clone(),
+        ..Options::default()
+    };
 
     let mut backend = create_backend(&cli)?;
     let state = MonitorState::create(options, backend.as_mut())?;
@@ -81,6 +177,20 @@ fn init_logging(cli: &CLIOptions) -> Result<()> {
     Ok(())
 }
 
+fn process_columns(cli: &CLIOptions) -> Result<HashSet<String>> {
+    let known = view::optional_process_columns();
+    cli.columns
+        .iter()
+        .map(|name| {
+            known
+                .iter()
+                .find(|(label, _)| label.eq_ignore_ascii_case(name))
+                .map(|(label, _)| label.to_string())
+                .ok_or_else(|| anyhow!("unknown process column {}", name))
+        })
+        .collect()
+}
+
 fn run_monitor<'b>(cli: &CLIOptions, state: MonitorState<'b>) -> Result<()> {
     let mut state = state;
 
@@ -89,7 +199,7 @@ fn run_monitor<'b>(cli: &CLIOptions, state: MonitorState<'b>) -> Result<()> {
         return Ok(());
     }
 
-    with_terminal(move |term| run_event_loop(term, &mut state))
+    with_terminal(!cli.no_mouse, move |term| run_event_loop(term, &mut state))
 }
 
 #[cfg(target_os = "linux")]
@@ -0,0 +1,14 @@
+//! Cumulative I/O counters.
+
+/// Cumulative byte counts for a network interface or disk.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IOTotals {
+    /// Bytes received (or read) since boot.
+    pub rx_boot: u64,
+    /// Bytes sent (or written) since boot.
+    pub tx_boot: u64,
+    /// Bytes received (or read) since the monitor started.
+    pub rx_start: u64,
+    /// Bytes sent (or written) since the monitor started.
+    pub tx_start: u64,
+}
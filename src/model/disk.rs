@@ -1,7 +1,11 @@
+use super::IOTotals;
+
 /// Disk IO statistics.
 #[derive(Debug, Clone)]
 pub struct DiskIO {
     pub name: String,
     pub rx_bytes: u64,
     pub tx_bytes: u64,
+    /// Cumulative bytes read and written.
+    pub totals: IOTotals,
 }
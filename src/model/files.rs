@@ -0,0 +1,75 @@
+//! Open files of a process.
+
+/// An open file descriptor.
+#[derive(Debug, Clone)]
+pub struct OpenFile {
+    pub fd: i32,
+    pub kind: OpenFileKind,
+    /// The path, or a description of the file for non-path descriptors.
+    pub name: String,
+    /// Current file offset.
+    pub pos: Option<u64>,
+    /// Access mode.
+    pub access: Option<FileAccess>,
+    /// Names of other status flags that are set (e.g. `append`, `nonblock`).
+    pub flags: Vec<&'static str>,
+}
+
+/// Kinds of open file descriptors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenFileKind {
+    /// A file, directory or device.
+    Path,
+    Pipe,
+    /// A socket, with its addresses if it could be found in the socket tables.
+    Socket(Option<SocketInfo>),
+    /// A descriptor with no inode (eventfd, epoll, etc.).
+    AnonInode,
+    /// A memory-backed file.
+    MemFD,
+    Other,
+}
+
+/// File access modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileAccess {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+/// Addresses and state of a socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocketInfo {
+    /// The protocol (`tcp`, `udp6`, `unix`, etc.).
+    pub protocol: &'static str,
+    pub local: Option<String>,
+    pub remote: Option<String>,
+    pub state: Option<String>,
+}
+
+impl OpenFileKind {
+    /// Get a short label for the descriptor kind.
+    pub fn label(&self) -> &'static str {
+        match self {
+            OpenFileKind::Path => "file",
+            OpenFileKind::Pipe => "pipe",
+            OpenFileKind::Socket(Some(sock)) => sock.protocol,
+            OpenFileKind::Socket(None) => "sock",
+            OpenFileKind::AnonInode => "anon",
+            OpenFileKind::MemFD => "memfd",
+            OpenFileKind::Other => "other",
+        }
+    }
+}
+
+impl FileAccess {
+    /// Get a short label for the access mode.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FileAccess::Read => "r",
+            FileAccess::Write => "w",
+            FileAccess::ReadWrite => "rw",
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Process filtering.
+use regex::{Regex, RegexBuilder};
+
+use super::Process;
+
+/// Filter for the process list, matching command lines, names, users and PIDs.
+#[derive(Debug, Clone)]
+pub struct ProcessFilter {
+    pattern: String,
+    regex: Regex,
+}
+
+impl ProcessFilter {
+    /// Create a filter from a pattern.
+    ///
+    /// The pattern is a regular expression if it is valid, and a substring
+    /// otherwise.  It is case-insensitive unless it contains uppercase letters.
+    pub fn new(pattern: &str) -> ProcessFilter {
+        let insensitive = !pattern.chars().any(|c| c.is_uppercase());
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(insensitive)
+            .build()
+            .unwrap_or_else(|_| {
+                RegexBuilder::new(&regex::escape(pattern))
+                    .case_insensitive(insensitive)
+                    .build()
+                    .expect("escaped pattern is invalid")
+            });
+        ProcessFilter {
+            pattern: pattern.to_string(),
+            regex,
+        }
+    }
+
+    /// Get the pattern the filter was created from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Check whether a process matches the filter.
+    pub fn matches(&self, proc: &Process, cmdline: Option<&str>, user: Option<&str>) -> bool {
+        self.regex.is_match(&proc.name)
+            || self.regex.is_match(&proc.pid.to_string())
+            || cmdline.is_some_and(|c| self.regex.is_match(c))
+            || user.is_some_and(|u| self.regex.is_match(u))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str, cmdline: Option<&str>, user: Option<&str>) -> bool {
+        ProcessFilter::new(pattern).matches(&Process::test(1234, None, name), cmdline, user)
+    }
+
+    #[test]
+    fn test_smart_case() {
+        assert!(matches("firefox", "Firefox", None, None));
+        assert!(matches("FIRE", "FIREFOX", None, None));
+        assert!(!matches("Fire", "firefox", None, None));
+    }
+
+    #[test]
+    fn test_regex() {
+        assert!(matches("^py.*3$", "python3", None, None));
+        assert!(!matches("^thon", "python3", None, None));
+        assert!(matches(
+            "train\\.py",
+            "python3",
+            Some("python3 train.py"),
+            None
+        ));
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        // invalid patterns match as plain substrings
+        assert!(matches("c++(", "gcc", Some("/usr/bin/c++(wrapper)"), None));
+        assert!(!matches("c++(", "gcc", Some("/usr/bin/c++"), None));
+        assert_eq!(ProcessFilter::new("c++(").pattern(), "c++(");
+    }
+
+    #[test]
+    fn test_fields() {
+        assert!(matches("234", "sleep", None, None));
+        assert!(matches("www", "nginx", None, Some("www-data")));
+        assert!(!matches("www", "nginx", Some("nginx: worker"), None));
+    }
+}
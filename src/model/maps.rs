@@ -0,0 +1,77 @@
+//! Process memory mappings.
+use std::collections::HashMap;
+
+/// A memory mapping of a process.
+#[derive(Debug, Clone)]
+pub struct MemoryMapping {
+    /// Start address of the mapping.
+    pub start: u64,
+    /// Size of the mapping (virtual memory).
+    pub size: u64,
+    /// Permissions, in `rwxp` form.
+    pub perms: String,
+    pub category: MapCategory,
+    /// The backing file or pseudo-path, if any.
+    pub path: Option<String>,
+    pub rss: u64,
+    pub pss: u64,
+    pub swap: u64,
+}
+
+/// Categories of memory mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MapCategory {
+    /// The process's executable.
+    Program,
+    Heap,
+    Stack,
+    Anonymous,
+    SharedLib,
+    File,
+    SharedMemory,
+    Device,
+    Other,
+}
+
+/// Memory usage totals for a category of mappings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MapTotals {
+    pub count: usize,
+    pub size: u64,
+    pub rss: u64,
+    pub pss: u64,
+    pub swap: u64,
+}
+
+impl MapCategory {
+    /// Get the display label for the category.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MapCategory::Program => "program",
+            MapCategory::Heap => "heap",
+            MapCategory::Stack => "stack",
+            MapCategory::Anonymous => "anonymous",
+            MapCategory::SharedLib => "shared libs",
+            MapCategory::File => "mapped files",
+            MapCategory::SharedMemory => "shared memory",
+            MapCategory::Device => "GPU / device",
+            MapCategory::Other => "other",
+        }
+    }
+}
+
+/// Total memory mappings by category, ordered by decreasing RSS.
+pub fn map_totals(maps: &[MemoryMapping]) -> Vec<(MapCategory, MapTotals)> {
+    let mut totals: HashMap<MapCategory, MapTotals> = HashMap::new();
+    for map in maps {
+        let t = totals.entry(map.category).or_default();
+        t.count += 1;
+        t.size += map.size;
+        t.rss += map.rss;
+        t.pss += map.pss;
+        t.swap += map.swap;
+    }
+    let mut totals: Vec<_> = totals.into_iter().collect();
+    totals.sort_by(|(c1, t1), (c2, t2)| t2.rss.cmp(&t1.rss).then(c1.cmp(c2)));
+    totals
+}
@@ -1,4 +1,5 @@
 //! Data model for memory information.
+use std::time::SystemTime;
 
 /// Basic memory usage statistics.
 #[derive(Debug, Clone)]
@@ -40,10 +41,19 @@ impl Memory {
     }
 }
 
+/// Processes killed by the kernel's out-of-memory killer since the monitor started.
+#[derive(Debug, Clone)]
+pub struct OomKills {
+    /// The number of processes killed.
+    pub count: u64,
+    /// When the most recent kill was observed.
+    pub last: Option<SystemTime>,
+}
+
 #[derive(Debug, Clone)]
 pub enum ExtendedMemory {
     None,
-    Linux(LinuxMemory),
+    Linux(Box<LinuxMemory>),
 }
 
 #[derive(Debug, Clone)]
@@ -55,4 +65,15 @@ pub struct LinuxMemory {
     pub shared: Option<u64>,
     pub reclaimable: Option<u64>,
     pub arc: Option<u64>,
+    pub swap_cached: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+    pub anon: Option<u64>,
+    pub mapped: u64,
+    pub slab: u64,
+    pub kernel_stack: Option<u64>,
+    pub page_tables: Option<u64>,
+    pub unevictable: Option<u64>,
+    pub committed: u64,
+    pub commit_limit: Option<u64>,
 }
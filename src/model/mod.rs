@@ -2,31 +2,46 @@
 use std::time::Duration;
 
 use anyhow::*;
-use uzers::{Users, UsersCache};
+use uzers::{Groups, Users, UsersCache};
 
+pub mod counters;
 pub mod cpu;
 pub mod disk;
+pub mod files;
+pub mod filter;
 pub mod fs;
 pub mod gpu;
 pub mod load;
+pub mod maps;
 pub mod memory;
 pub mod network;
 pub mod options;
 pub mod process;
+pub mod selection;
 pub mod source;
 pub mod state;
 pub mod swap;
+pub mod tree;
 
+pub use counters::IOTotals;
 pub use cpu::CPU;
 pub use disk::DiskIO;
+pub use files::OpenFile;
+pub use filter::ProcessFilter;
 pub use fs::Filesystem;
 pub use gpu::GPUStats;
 #[allow(unused_imports)]
 pub use load::{LoadAvg, Pressure, SystemPressure};
-pub use memory::{ExtendedMemory, Memory};
+pub use maps::MemoryMapping;
+pub use memory::{ExtendedMemory, Memory, OomKills};
 pub use network::NetworkStats;
-pub use options::Options;
-pub use process::{ProcSortOrder, Process, ProcessCommandInfo};
+pub use options::{CpuPctMode, IOCounterMode, Options, ThreadMode};
+pub use process::{
+    ContextSwitches, IOPriority, ProcSortOrder, Process, ProcessChange, ProcessChurn,
+    ProcessCommandInfo, ProcessDetails, ProcessFdUsage, ProcessMemory, ProcessOom, ProcessSignal,
+    ResourceLimit,
+};
+pub use selection::{ProcessSelection, ScrollPosition};
 pub use source::{
     GPUInfo, NetworkInfo, RunningProcesses, StorageInfo, SystemInfo, SystemResources,
 };
@@ -44,5 +59,7 @@ pub trait MonitorData:
     SystemInfo + SystemResources + RunningProcesses + NetworkInfo + StorageInfo + GPUInfo
 {
     fn backend(&self) -> &dyn MonitorBackend;
+    fn options(&self) -> &Options;
     fn lookup_user(&self, uid: u32) -> Result<Option<String>>;
+    fn lookup_group(&self, gid: u32) -> Result<Option<String>>;
 }
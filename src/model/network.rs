@@ -1,3 +1,5 @@
+use super::IOTotals;
+
 /// Network statistics.
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -7,4 +9,6 @@ pub struct NetworkStats {
     pub tx_bytes: u64,
     pub rx_packets: u64,
     pub tx_packets: u64,
+    /// Cumulative bytes received and sent.
+    pub totals: IOTotals,
 }
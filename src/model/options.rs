@@ -1,17 +1,122 @@
 //! Options for models and backend state.
-use std::time::Duration;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime};
+
+use super::ProcessFilter;
 
 /// Struct containing the options for the system viewer.  These are initialized from
 /// the command line and defaults, and some can be modified interactively.
 pub struct Options {
     /// Refresh interval.
     pub refresh: Duration,
+    /// Which I/O counters to display in the network and disk tables.
+    pub io_counters: IOCounterMode,
+    /// Whether to show a total row in the network and disk tables.
+    pub io_totals: bool,
+    /// Optional process table columns to display (by label).
+    pub proc_columns: HashSet<String>,
+    /// Fraction of the open file limit at which to highlight processes (0 to disable).
+    pub fd_warn_frac: f32,
+    /// Filter for the process list.
+    pub proc_filter: Option<ProcessFilter>,
+    /// Only list processes owned by this user (name or UID).
+    pub proc_user: Option<String>,
+    /// Processes pinned to the top of the process table, with their start times so
+    /// a reused PID is not pinned.  The start time is [None] until it is known.
+    pub pin_pids: HashMap<u32, Option<SystemTime>>,
+    /// Patterns pinning matching processes to the top of the process table.
+    pub pin_patterns: Vec<ProcessFilter>,
+    /// Whether to list processes or their individual threads.
+    pub threads: ThreadMode,
+    /// Whether to display the process table as a tree.
+    pub proc_tree: bool,
+    /// Whether to show subtree-aggregated CPU and memory in the process tree.
+    pub tree_totals: bool,
+    /// How process CPU utilization is expressed.
+    pub cpu_mode: CpuPctMode,
+    /// Whether to show full command lines instead of compact ones.
+    pub full_commands: bool,
+    /// Whether to hide kernel threads from the process table.
+    pub hide_kernel: bool,
+    /// Whether to hide processes with no CPU or I/O activity in the last refresh.
+    pub hide_idle: bool,
+    /// Environment variable to show in a process table column.
+    pub env_column: Option<String>,
+}
+
+/// Display mode for network and disk I/O counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IOCounterMode {
+    /// Bytes per second over the last refresh.
+    Rate,
+    /// Total bytes since the monitor started.
+    SinceStart,
+    /// Total bytes since the system booted.
+    SinceBoot,
+}
+
+impl IOCounterMode {
+    /// Get the next mode in the cycle.
+    pub fn next(self) -> IOCounterMode {
+        match self {
+            IOCounterMode::Rate => IOCounterMode::SinceStart,
+            IOCounterMode::SinceStart => IOCounterMode::SinceBoot,
+            IOCounterMode::SinceBoot => IOCounterMode::Rate,
+        }
+    }
+}
+
+/// Scale of process CPU percentages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuPctMode {
+    /// Percent of a single core, so a multi-threaded process can exceed 100% (top's
+    /// Irix mode).
+    PerCore,
+    /// Percent of the whole machine's CPU capacity (top's Solaris mode).
+    OfTotal,
+}
+
+impl CpuPctMode {
+    /// Get the other mode.
+    pub fn next(self) -> CpuPctMode {
+        match self {
+            CpuPctMode::PerCore => CpuPctMode::OfTotal,
+            CpuPctMode::OfTotal => CpuPctMode::PerCore,
+        }
+    }
+}
+
+/// Which tasks to list in the process table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadMode {
+    /// List processes.
+    Processes,
+    /// List the threads of all processes.
+    AllThreads,
+    /// List the threads of a single process.
+    ProcessThreads(u32),
 }
 
 impl Default for Options {
     fn default() -> Options {
         Options {
             refresh: Duration::from_millis(2500),
+            io_counters: IOCounterMode::Rate,
+            io_totals: false,
+            proc_columns: HashSet::new(),
+            fd_warn_frac: 0.0,
+            proc_filter: None,
+            proc_user: None,
+            pin_pids: HashMap::new(),
+            pin_patterns: Vec::new(),
+            threads: ThreadMode::Processes,
+            proc_tree: false,
+            tree_totals: false,
+            cpu_mode: CpuPctMode::PerCore,
+            full_commands: false,
+            hide_kernel: false,
+            hide_idle: false,
+            env_column: None,
         }
     }
 }
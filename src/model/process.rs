@@ -1,9 +1,20 @@
 //! Process data model.
-use std::{cell::RefCell, cmp::Ordering, ops::Deref, time::Duration};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    time::{Duration, SystemTime},
+};
 
-use crate::backend::BackendResult;
+use crate::backend::{error::BackendErrorFilter, BackendResult};
 
-use super::{MonitorState, SystemResources};
+use super::tree::{tree_order, ProcessTreeNode};
+use super::{MonitorData, MonitorState, RunningProcesses, SystemResources};
+
+/// Number of processes (by RSS) to load detailed memory usage for when sorting
+/// by PSS, since reading it is expensive.
+const MEM_DETAIL_TOP_N: usize = 100;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ProcessCounts {
@@ -15,7 +26,17 @@ pub struct ProcessCounts {
 /// Process list
 pub struct ProcessList {
     order: ProcSortOrder,
+    /// Whether the sort order is reversed.
+    reverse: bool,
+    /// Precomputed sort keys, for orders that need more than the process record.
+    sort_keys: HashMap<u32, String>,
     procs: Vec<Process>,
+    /// Collapsed process IDs, if the list is displayed as a tree.
+    tree: Option<HashSet<u32>>,
+    /// Number of processes hidden by the kernel thread and idle toggles.
+    hidden: usize,
+    /// Pinned processes, listed at the top regardless of the sort order.
+    pinned: HashSet<u32>,
     counts: RefCell<Option<ProcessCounts>>,
 }
 
@@ -26,6 +47,33 @@ pub enum ProcSortOrder {
     Memory,
     IO,
     Time,
+    PSS,
+    Faults,
+    CtxSwitches,
+    SchedWait,
+    Threads,
+    Nice,
+    Age,
+    Pid,
+    Tgid,
+    User,
+    Name,
+    Command,
+    State,
+    Virt,
+    Res,
+}
+
+/// Process scheduling policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedPolicy {
+    Other,
+    Fifo,
+    RoundRobin,
+    Batch,
+    Idle,
+    Deadline,
+    Unknown(u32),
 }
 
 #[allow(dead_code)]
@@ -33,8 +81,16 @@ pub enum ProcSortOrder {
 pub struct Process {
     pub pid: u32,
     pub ppid: Option<u32>,
+    /// The owning process, when listing threads.
+    pub tgid: Option<u32>,
     pub name: String,
     pub uid: Option<u32>,
+    /// Whether this is a kernel thread.
+    pub kernel_thread: bool,
+    /// Whether the process started or exited since the previous refresh.
+    pub change: ProcessChange,
+    /// When the process started.
+    pub start_time: Option<SystemTime>,
 
     pub status: char,
     pub cpu_util: f32,
@@ -45,9 +101,162 @@ pub struct Process {
     pub mem_util: f32,
     pub mem_rss: u64,
     pub mem_virt: u64,
+    /// Detailed memory usage, if it has been loaded.
+    pub mem_detail: Option<ProcessMemory>,
 
     pub io_read: Option<u64>,
     pub io_write: Option<u64>,
+
+    /// Minor page faults per second.
+    pub minor_faults: Option<u64>,
+    /// Major page faults per second.
+    pub major_faults: Option<u64>,
+    /// Voluntary context switches per second, if they have been loaded.
+    pub vol_ctx_switches: Option<u64>,
+    /// Involuntary context switches per second, if they have been loaded.
+    pub invol_ctx_switches: Option<u64>,
+    /// Fraction of the refresh window spent waiting on a run queue (summed over
+    /// threads), if it has been loaded.
+    pub sched_wait: Option<f32>,
+
+    /// Number of threads.
+    pub threads: Option<u32>,
+    /// Kernel scheduling priority.
+    pub priority: Option<i32>,
+    /// Nice value.
+    pub nice: Option<i32>,
+    /// Scheduling policy.
+    pub policy: Option<SchedPolicy>,
+    /// Real-time priority (0 for non-real-time policies).
+    pub rt_priority: Option<u32>,
+    /// CPU the process last ran on.
+    pub processor: Option<u32>,
+
+    /// Position in the process tree, when the list is displayed as a tree.
+    pub tree: Option<ProcessTreeNode>,
+}
+
+/// How a process has changed since the previous refresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessChange {
+    #[default]
+    Unchanged,
+    /// The process started since the previous refresh.
+    Started,
+    /// The process has exited, and is listed for one more refresh.
+    Exited,
+}
+
+/// Rates of processes starting and exiting, per second.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessChurn {
+    pub started: f32,
+    pub exited: f32,
+}
+
+/// Detailed process memory usage.  This is expensive to collect, so it is only
+/// loaded for processes that are displayed or sorted on it.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessMemory {
+    /// Proportional set size (resident memory with shared pages split among sharers).
+    pub pss: Option<u64>,
+    /// Unique set size (private clean and dirty pages).
+    pub uss: Option<u64>,
+    /// Shared clean and dirty pages.
+    pub shared: Option<u64>,
+    /// Memory swapped out.
+    pub swap: Option<u64>,
+}
+
+/// Out-of-memory killer scores for a process.
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessOom {
+    /// The current OOM score (higher is more likely to be killed).
+    pub score: u32,
+    /// The user-controlled adjustment to the OOM score.
+    pub adj: i32,
+}
+
+/// Context switch counts (or rates) for a process.
+#[derive(Debug, Clone, Copy)]
+pub struct ContextSwitches {
+    pub voluntary: u64,
+    pub involuntary: u64,
+}
+
+/// File descriptor usage and related resource limits for a process.
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessFdUsage {
+    /// Number of open file descriptors.
+    pub open: u64,
+    /// Open file limit (`RLIMIT_NOFILE`).
+    pub nofile: ResourceLimit,
+    /// Process limit (`RLIMIT_NPROC`).
+    pub nproc: ResourceLimit,
+}
+
+/// Soft and hard values of a resource limit ([None] for unlimited or unknown).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceLimit {
+    pub soft: Option<u64>,
+    pub hard: Option<u64>,
+}
+
+impl ProcessFdUsage {
+    /// Get the open file descriptors as a fraction of the soft limit.
+    pub fn nofile_frac(&self) -> Option<f32> {
+        self.nofile
+            .soft
+            .filter(|l| *l > 0)
+            .map(|l| self.open as f32 / l as f32)
+    }
+}
+
+/// Miscellaneous process attributes shown in the process detail view.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDetails {
+    /// Current working directory.
+    pub cwd: Option<String>,
+    /// Real group ID.
+    pub gid: Option<u32>,
+    /// Control group path.
+    pub cgroup: Option<String>,
+    /// Total bytes read since the process started.
+    pub io_read_total: Option<u64>,
+    /// Total bytes written since the process started.
+    pub io_write_total: Option<u64>,
+    /// Number of environment variables.
+    pub env_count: Option<usize>,
+}
+
+/// Signals that can be sent to processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    Term,
+    Kill,
+    Int,
+    Hup,
+    Stop,
+    Cont,
+    Usr1,
+    Usr2,
+}
+
+/// I/O scheduling class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IOClass {
+    /// No class set (derived from the CPU nice value).
+    None,
+    RealTime,
+    BestEffort,
+    Idle,
+}
+
+/// I/O scheduling class and priority level (0–7, lower is higher priority).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IOPriority {
+    pub class: IOClass,
+    pub level: u8,
 }
 
 #[allow(dead_code)]
@@ -57,6 +266,13 @@ pub struct ProcessCommandInfo {
     pub cmdline: Vec<String>,
 }
 
+impl Process {
+    /// Query whether the process used no CPU and did no I/O in the last refresh.
+    pub fn is_idle(&self) -> bool {
+        self.cpu_util == 0.0 && self.io_read.unwrap_or(0) == 0 && self.io_write.unwrap_or(0) == 0
+    }
+}
+
 impl ProcessList {
     pub(super) fn create<'a, 'b>(
         state: &'a MonitorState<'b>,
@@ -65,6 +281,30 @@ impl ProcessList {
     where
         'b: 'a,
     {
+        let mut procs = procs;
+        let pinned: HashSet<u32> = procs
+            .iter()
+            .filter(|p| is_pinned(state, p))
+            .map(|p| p.pid)
+            .collect();
+        // pinned processes are always listed, so filters do not apply to them
+        if state.options.proc_filter.is_some() || state.options.proc_user.is_some() {
+            procs.retain(|p| pinned.contains(&p.pid) || filter_process(state, p));
+        }
+        let listed = procs.len();
+        if state.options.hide_kernel {
+            procs.retain(|p| pinned.contains(&p.pid) || !p.kernel_thread);
+        }
+        if state.options.hide_idle {
+            // keep the selection, so it does not vanish when it goes quiet
+            procs.retain(|p| {
+                !p.is_idle()
+                    || p.change == ProcessChange::Exited
+                    || state.selection.pid == Some(p.pid)
+                    || pinned.contains(&p.pid)
+            });
+        }
+        let hidden = listed - procs.len();
         let order = if let Some(order) = state.proc_sort {
             order
         } else if state.global_cpu()?.utilization >= 0.9 {
@@ -74,9 +314,45 @@ impl ProcessList {
         } else {
             ProcSortOrder::CPU
         };
+        // scheduler counters are only read on demand, so load them to sort by them
+        if order == ProcSortOrder::CtxSwitches {
+            for proc in procs.iter_mut() {
+                if let Some(cs) = state.process_ctx_switches(proc.pid).acceptable_to_opt()? {
+                    proc.vol_ctx_switches = Some(cs.voluntary);
+                    proc.invol_ctx_switches = Some(cs.involuntary);
+                }
+            }
+        }
+        if order == ProcSortOrder::SchedWait {
+            for proc in procs.iter_mut() {
+                proc.sched_wait = state.process_sched_wait(proc.pid).acceptable_to_opt()?;
+            }
+        }
+        if order == ProcSortOrder::PSS {
+            procs.sort_by(proc_sort_mem);
+            for proc in procs.iter_mut().take(MEM_DETAIL_TOP_N) {
+                proc.mem_detail = state.process_memory(proc.pid).acceptable_to_opt()?;
+            }
+        }
+        let sort_keys = match order {
+            ProcSortOrder::User => procs.iter().map(|p| (p.pid, user_key(state, p))).collect(),
+            ProcSortOrder::Command => procs
+                .iter()
+                .map(|p| (p.pid, command_key(state, p)))
+                .collect(),
+            _ => HashMap::new(),
+        };
         Ok(ProcessList {
             order,
+            reverse: state.proc_sort_reverse,
+            sort_keys,
             procs,
+            tree: state
+                .options
+                .proc_tree
+                .then(|| state.proc_collapsed.clone()),
+            hidden,
+            pinned,
             counts: RefCell::default(),
         })
     }
@@ -85,18 +361,80 @@ impl ProcessList {
         self.order
     }
 
+    /// Query whether the list is displayed as a process tree.
+    pub fn is_tree(&self) -> bool {
+        self.tree.is_some()
+    }
+
+    /// Get the number of processes hidden by the kernel thread and idle toggles.
+    pub fn hidden(&self) -> usize {
+        self.hidden
+    }
+
+    /// Get the number of pinned processes, which are listed first once sorted.
+    pub fn pinned(&self) -> usize {
+        self.procs
+            .iter()
+            .take_while(|p| self.pinned.contains(&p.pid))
+            .count()
+    }
+
+    /// Query whether a process is pinned.
+    pub fn is_pinned(&self, pid: u32) -> bool {
+        self.pinned.contains(&pid)
+    }
+
+    /// Query whether the sort order is reversed.
+    pub fn is_reversed(&self) -> bool {
+        self.reverse
+    }
+
     /// Sort the list of processes.
     ///
-    /// Returns the effective sort order.
+    /// Ties are broken by PID, so the order is stable across refreshes.
     pub fn sort(&mut self) {
-        let sort_fn = match self.order {
+        let sort_fn: fn(&Process, &Process) -> Ordering = match self.order {
             ProcSortOrder::CPU => proc_sort_cpu,
             ProcSortOrder::Memory => proc_sort_mem,
             ProcSortOrder::IO => proc_sort_io,
             ProcSortOrder::Time => proc_sort_time,
+            ProcSortOrder::PSS => proc_sort_pss,
+            ProcSortOrder::Faults => proc_sort_faults,
+            ProcSortOrder::CtxSwitches => proc_sort_ctx_switches,
+            ProcSortOrder::SchedWait => proc_sort_sched_wait,
+            ProcSortOrder::Threads => proc_sort_threads,
+            ProcSortOrder::Nice => proc_sort_nice,
+            ProcSortOrder::Age => proc_sort_age,
+            ProcSortOrder::Pid => |p1, p2| p1.pid.cmp(&p2.pid),
+            ProcSortOrder::Tgid => |p1, p2| p1.tgid.cmp(&p2.tgid),
+            ProcSortOrder::Name => |p1, p2| p1.name.cmp(&p2.name),
+            ProcSortOrder::State => |p1, p2| p1.status.cmp(&p2.status),
+            ProcSortOrder::Virt => |p1, p2| p2.mem_virt.cmp(&p1.mem_virt),
+            ProcSortOrder::Res => |p1, p2| p2.mem_rss.cmp(&p1.mem_rss),
+            // sorted by the precomputed keys
+            ProcSortOrder::User | ProcSortOrder::Command => |_, _| Ordering::Equal,
         };
 
-        self.procs.sort_by(sort_fn);
+        let keys = &self.sort_keys;
+        let reverse = self.reverse;
+        self.procs.sort_by(|p1, p2| {
+            let ord = sort_fn(p1, p2).then_with(|| keys.get(&p1.pid).cmp(&keys.get(&p2.pid)));
+            let ord = if reverse { ord.reverse() } else { ord };
+            ord.then(p1.pid.cmp(&p2.pid))
+        });
+        if self.tree.is_some() {
+            // count before collapsed subtrees are removed from the list
+            self.counts();
+        }
+        let pinned = &self.pinned;
+        let (mut procs, mut rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.procs)
+            .into_iter()
+            .partition(|p| pinned.contains(&p.pid));
+        if let Some(collapsed) = &self.tree {
+            rest = tree_order(rest, collapsed);
+        }
+        procs.extend(rest);
+        self.procs = procs;
     }
 
     pub fn counts(&self) -> ProcessCounts {
@@ -106,6 +444,9 @@ impl ProcessList {
         } else {
             let mut counts = ProcessCounts::default();
             for proc in &self.procs {
+                if proc.change == ProcessChange::Exited {
+                    continue;
+                }
                 match proc.status {
                     'R' => counts.running += 1,
                     'S' => counts.sleeping += 1,
@@ -118,6 +459,68 @@ impl ProcessList {
     }
 }
 
+/// Get the user name (or UID) to sort a process by.
+fn user_key(state: &MonitorState<'_>, proc: &Process) -> String {
+    match proc.uid {
+        Some(uid) => state
+            .lookup_user(uid)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| uid.to_string()),
+        None => String::new(),
+    }
+}
+
+/// Get the command line to sort a process by.
+fn command_key(state: &MonitorState<'_>, proc: &Process) -> String {
+    state
+        .process_cmd_info(proc.pid)
+        .ok()
+        .map(|c| c.cmdline.join(" "))
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| format!("[{}]", proc.name))
+}
+
+/// Check whether a process is pinned by PID or pattern.
+fn is_pinned(state: &MonitorState<'_>, proc: &Process) -> bool {
+    let opts = &state.options;
+    match opts.pin_pids.get(&proc.pid) {
+        Some(None) => return true,
+        Some(Some(start)) if proc.start_time == Some(*start) => return true,
+        _ => (),
+    }
+    if opts.pin_patterns.is_empty() {
+        return false;
+    }
+    let cmdline = state
+        .process_cmd_info(proc.pid)
+        .ok()
+        .map(|c| c.cmdline.join(" "));
+    opts.pin_patterns
+        .iter()
+        .any(|f| f.matches(proc, cmdline.as_deref(), None))
+}
+
+/// Check whether a process passes the active process and user filters.
+fn filter_process(state: &MonitorState<'_>, proc: &Process) -> bool {
+    let user = proc.uid.and_then(|u| state.lookup_user(u).ok().flatten());
+    if let Some(want) = &state.options.proc_user {
+        let uid_match = proc.uid.is_some_and(|u| u.to_string() == *want);
+        if !uid_match && user.as_ref() != Some(want) {
+            return false;
+        }
+    }
+    if let Some(filter) = &state.options.proc_filter {
+        let cmdline = state
+            .process_cmd_info(proc.pid)
+            .ok()
+            .map(|c| c.cmdline.join(" "));
+        filter.matches(proc, cmdline.as_deref(), user.as_deref())
+    } else {
+        true
+    }
+}
+
 fn proc_sort_cpu(p1: &Process, p2: &Process) -> Ordering {
     p2.cpu_util.total_cmp(&p1.cpu_util)
 }
@@ -126,21 +529,208 @@ fn proc_sort_mem(p1: &Process, p2: &Process) -> Ordering {
     p2.mem_util.total_cmp(&p1.mem_util)
 }
 
+fn proc_sort_pss(p1: &Process, p2: &Process) -> Ordering {
+    // processes without details are outside the top N by RSS, so RSS is a good stand-in
+    let m1 = p1
+        .mem_detail
+        .as_ref()
+        .and_then(|m| m.pss)
+        .unwrap_or(p1.mem_rss);
+    let m2 = p2
+        .mem_detail
+        .as_ref()
+        .and_then(|m| m.pss)
+        .unwrap_or(p2.mem_rss);
+    m2.cmp(&m1)
+}
+
 fn proc_sort_io(p1: &Process, p2: &Process) -> Ordering {
-    if let (Some(r1), Some(r2), Some(w1), Some(w2)) =
-        (p1.io_read, p2.io_read, p1.io_write, p2.io_write)
-    {
-        (r2 + w2).cmp(&(r1 + w1))
-    } else {
-        Ordering::Equal
-    }
+    // processes without I/O data sort last
+    let io1 = p1.io_read.zip(p1.io_write).map(|(r, w)| r + w);
+    let io2 = p2.io_read.zip(p2.io_write).map(|(r, w)| r + w);
+    io2.cmp(&io1)
+}
+
+fn proc_sort_faults(p1: &Process, p2: &Process) -> Ordering {
+    // major faults are the expensive ones, so they take precedence
+    (p2.major_faults, p2.minor_faults).cmp(&(p1.major_faults, p1.minor_faults))
+}
+
+fn proc_sort_ctx_switches(p1: &Process, p2: &Process) -> Ordering {
+    let c1 = p1
+        .vol_ctx_switches
+        .zip(p1.invol_ctx_switches)
+        .map(|(v, i)| v + i);
+    let c2 = p2
+        .vol_ctx_switches
+        .zip(p2.invol_ctx_switches)
+        .map(|(v, i)| v + i);
+    c2.cmp(&c1)
+}
+
+fn proc_sort_sched_wait(p1: &Process, p2: &Process) -> Ordering {
+    let w1 = p1.sched_wait.unwrap_or(-1.0);
+    let w2 = p2.sched_wait.unwrap_or(-1.0);
+    w2.total_cmp(&w1)
+}
+
+fn proc_sort_threads(p1: &Process, p2: &Process) -> Ordering {
+    p2.threads.cmp(&p1.threads)
+}
+
+fn proc_sort_nice(p1: &Process, p2: &Process) -> Ordering {
+    // lowest nice (highest priority) first, with unknown values last
+    let n1 = p1.nice.unwrap_or(i32::MAX);
+    let n2 = p2.nice.unwrap_or(i32::MAX);
+    n1.cmp(&n2)
+}
+
+fn proc_sort_age(p1: &Process, p2: &Process) -> Ordering {
+    // newest first
+    p2.start_time.cmp(&p1.start_time)
 }
 
 fn proc_sort_time(p1: &Process, p2: &Process) -> Ordering {
-    if let (Some(t1), Some(t2)) = (p1.cpu_time, p2.cpu_time) {
-        t2.cmp(&t1)
+    // processes without CPU times sort last
+    p2.cpu_time.cmp(&p1.cpu_time)
+}
+
+impl ProcessSignal {
+    /// All supported signals, in the order they are offered.
+    pub const ALL: [ProcessSignal; 8] = [
+        ProcessSignal::Term,
+        ProcessSignal::Kill,
+        ProcessSignal::Int,
+        ProcessSignal::Hup,
+        ProcessSignal::Stop,
+        ProcessSignal::Cont,
+        ProcessSignal::Usr1,
+        ProcessSignal::Usr2,
+    ];
+
+    /// Get the signal name (without the `SIG` prefix).
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProcessSignal::Term => "TERM",
+            ProcessSignal::Kill => "KILL",
+            ProcessSignal::Int => "INT",
+            ProcessSignal::Hup => "HUP",
+            ProcessSignal::Stop => "STOP",
+            ProcessSignal::Cont => "CONT",
+            ProcessSignal::Usr1 => "USR1",
+            ProcessSignal::Usr2 => "USR2",
+        }
+    }
+}
+
+impl IOPriority {
+    pub fn new(class: IOClass, level: u8) -> IOPriority {
+        IOPriority { class, level }
+    }
+
+    /// Parse an I/O priority in `ionice` style: `rt/N`, `be/N`, `idle` or `none`.
+    pub fn parse(text: &str) -> Option<IOPriority> {
+        let text = text.trim().to_lowercase();
+        let (class, level) = match text.split_once('/') {
+            Some((c, l)) => (c, Some(l.trim().parse::<u8>().ok().filter(|l| *l <= 7)?)),
+            None => (text.as_str(), None),
+        };
+        match (class.trim(), level) {
+            ("rt" | "realtime", l) => Some(IOPriority::new(IOClass::RealTime, l.unwrap_or(4))),
+            ("be" | "best-effort", l) => Some(IOPriority::new(IOClass::BestEffort, l.unwrap_or(4))),
+            ("idle", None) => Some(IOPriority::new(IOClass::Idle, 0)),
+            ("none", None) => Some(IOPriority::new(IOClass::None, 0)),
+            _ => None,
+        }
+    }
+
+    /// Get a short label for the priority.
+    pub fn label(&self) -> String {
+        match self.class {
+            IOClass::None => "none".into(),
+            IOClass::RealTime => format!("rt/{}", self.level),
+            IOClass::BestEffort => format!("be/{}", self.level),
+            IOClass::Idle => "idle".into(),
+        }
+    }
+}
+
+/// Format a list of CPUs in the kernel's list format (e.g. `0-3,6`).
+pub fn format_cpu_list(cpus: &[u32]) -> String {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for cpu in cpus {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == *cpu => *end = *cpu,
+            _ => ranges.push((*cpu, *cpu)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The number of CPUs an affinity mask can hold (glibc's `CPU_SETSIZE`).
+pub const MAX_CPUS: u32 = 1024;
+
+/// Parse a list of CPUs in the kernel's list format, rejecting CPUs at or
+/// above [`MAX_CPUS`].
+pub fn parse_cpu_list(text: &str) -> Option<Vec<u32>> {
+    let mut cpus = Vec::new();
+    for part in text.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start: u32 = start.trim().parse().ok()?;
+                let end: u32 = end.trim().parse().ok()?;
+                if end < start || end >= MAX_CPUS {
+                    return None;
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(part.parse().ok().filter(|c| *c < MAX_CPUS)?),
+        }
+    }
+    cpus.sort();
+    cpus.dedup();
+    if cpus.is_empty() {
+        None
     } else {
-        Ordering::Equal
+        Some(cpus)
+    }
+}
+
+impl SchedPolicy {
+    /// Convert a Linux scheduling policy number to a policy.
+    pub fn from_linux(policy: u32) -> SchedPolicy {
+        match policy {
+            0 => SchedPolicy::Other,
+            1 => SchedPolicy::Fifo,
+            2 => SchedPolicy::RoundRobin,
+            3 => SchedPolicy::Batch,
+            5 => SchedPolicy::Idle,
+            6 => SchedPolicy::Deadline,
+            p => SchedPolicy::Unknown(p),
+        }
+    }
+
+    /// Get a short label for the policy.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SchedPolicy::Other => "TS",
+            SchedPolicy::Fifo => "FF",
+            SchedPolicy::RoundRobin => "RR",
+            SchedPolicy::Batch => "B",
+            SchedPolicy::Idle => "IDL",
+            SchedPolicy::Deadline => "DL",
+            SchedPolicy::Unknown(_) => "?",
+        }
     }
 }
 
@@ -151,3 +741,91 @@ impl Deref for ProcessList {
         &self.procs
     }
 }
+
+#[cfg(test)]
+impl Process {
+    /// Create a process with no other details, for tests.
+    pub(crate) fn test(pid: u32, ppid: Option<u32>, name: &str) -> Process {
+        Process {
+            pid,
+            ppid,
+            tgid: None,
+            name: name.into(),
+            uid: None,
+            kernel_thread: false,
+            change: ProcessChange::Unchanged,
+            start_time: None,
+            status: 'S',
+            cpu_util: 0.0,
+            cpu_time: None,
+            cpu_utime: None,
+            cpu_stime: None,
+            mem_util: 0.0,
+            mem_rss: 0,
+            mem_virt: 0,
+            mem_detail: None,
+            io_read: None,
+            io_write: None,
+            minor_faults: None,
+            major_faults: None,
+            vol_ctx_switches: None,
+            invol_ctx_switches: None,
+            sched_wait: None,
+            threads: None,
+            priority: None,
+            nice: None,
+            policy: None,
+            rt_priority: None,
+            processor: None,
+            tree: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,6"), Some(vec![0, 1, 2, 3, 6]));
+        assert_eq!(parse_cpu_list(" 5, 1-2 ,2,"), Some(vec![1, 2, 5]));
+        assert_eq!(parse_cpu_list("7"), Some(vec![7]));
+        assert_eq!(parse_cpu_list(""), None);
+        assert_eq!(parse_cpu_list("3-1"), None);
+        assert_eq!(parse_cpu_list("a-b"), None);
+        assert_eq!(parse_cpu_list("-1"), None);
+    }
+
+    #[test]
+    fn test_parse_cpu_list_bounds() {
+        assert_eq!(parse_cpu_list("1023").map(|c| c.len()), Some(1));
+        assert_eq!(parse_cpu_list("1024"), None);
+        assert_eq!(parse_cpu_list("0-4294967295"), None);
+    }
+
+    #[test]
+    fn test_format_cpu_list() {
+        assert_eq!(format_cpu_list(&[0, 1, 2, 3, 6]), "0-3,6");
+        assert_eq!(format_cpu_list(&[1, 3, 5]), "1,3,5");
+        assert_eq!(format_cpu_list(&[4]), "4");
+        assert_eq!(format_cpu_list(&[]), "");
+        assert_eq!(
+            format_cpu_list(&parse_cpu_list("8-9,0-2").unwrap()),
+            "0-2,8-9"
+        );
+    }
+
+    #[test]
+    fn test_parse_io_priority() {
+        let parse = |text| IOPriority::parse(text).map(|p| p.label());
+        assert_eq!(parse("be/2"), Some("be/2".into()));
+        assert_eq!(parse(" RT / 0 "), Some("rt/0".into()));
+        assert_eq!(parse("best-effort"), Some("be/4".into()));
+        assert_eq!(parse("idle"), Some("idle".into()));
+        assert_eq!(parse("none"), Some("none".into()));
+        assert_eq!(parse("be/8"), None);
+        assert_eq!(parse("idle/3"), None);
+        assert_eq!(parse("fast"), None);
+    }
+}
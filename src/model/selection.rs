@@ -0,0 +1,259 @@
+//! Process table selection and scroll positions.
+use std::cell::Cell;
+
+use super::Process;
+
+/// Selection and scroll position in the process table.
+///
+/// The selection tracks a process ID, so it follows the process as the table
+/// is re-sorted.  The row-based fields are updated when the table is rendered.
+#[derive(Debug, Default)]
+pub struct ProcessSelection {
+    /// The selected process.
+    pub pid: Option<u32>,
+    /// Index of the selected process in the last rendered list.
+    row: Cell<usize>,
+    /// Index of the first displayed row.
+    offset: Cell<usize>,
+    /// Number of rows displayed in the last render.
+    page: Cell<usize>,
+    /// Number of rows fixed at the top of the display (pinned processes).
+    fixed: Cell<usize>,
+    /// Whether the selected process was missing from the last rendered list.
+    exited: Cell<bool>,
+}
+
+impl ProcessSelection {
+    /// Update the selection's position from the list about to be displayed.
+    /// The first `fixed` rows are always displayed, and the rest scroll
+    /// beneath them.
+    ///
+    /// Returns the index of the selected row, if it is in the list.
+    pub fn sync(&self, procs: &[Process], page: usize, fixed: usize) -> Option<usize> {
+        let index = self
+            .pid
+            .and_then(|pid| procs.iter().position(|p| p.pid == pid));
+        self.exited.set(self.pid.is_some() && index.is_none());
+        if let Some(i) = index {
+            self.row.set(i);
+        }
+        self.page.set(page);
+        self.fixed.set(fixed);
+
+        let page = page.saturating_sub(fixed);
+        let mut offset = self.offset.get();
+        if let Some(i) = index.and_then(|i| i.checked_sub(fixed)) {
+            if i < offset {
+                offset = i;
+            } else if page > 0 && i >= offset + page {
+                offset = i + 1 - page;
+            }
+        }
+        offset = offset.min(procs.len().saturating_sub(fixed + page));
+        self.offset.set(offset);
+        index
+    }
+
+    /// Get the index of the first displayed row below the fixed rows.
+    pub fn offset(&self) -> usize {
+        self.offset.get()
+    }
+
+    /// Get the list index of a displayed row.
+    pub fn row_index(&self, row: usize) -> usize {
+        if row < self.fixed.get() {
+            row
+        } else {
+            row + self.offset.get()
+        }
+    }
+
+    /// Query whether the selected process has exited (or been filtered out).
+    pub fn exited(&self) -> bool {
+        self.exited.get()
+    }
+
+    /// Move the selection by a number of rows in the displayed list.
+    pub fn move_by(&mut self, procs: &[Process], delta: isize) {
+        if procs.is_empty() {
+            return;
+        }
+        let current = match self.pid {
+            Some(pid) => procs
+                .iter()
+                .position(|p| p.pid == pid)
+                .unwrap_or(self.row.get()),
+            // the first move selects the top row
+            None => return self.select(procs, 0),
+        };
+        self.select(procs, current.saturating_add_signed(delta));
+    }
+
+    /// Move the selection by a page of rows.
+    pub fn move_pages(&mut self, procs: &[Process], pages: isize) {
+        let page = self.page.get().max(1) as isize;
+        self.move_by(procs, pages * page);
+    }
+
+    /// Select the process at a row in the displayed list (clamped to the list).
+    pub fn select(&mut self, procs: &[Process], row: usize) {
+        if let Some(last) = procs.len().checked_sub(1) {
+            let row = row.min(last);
+            self.pid = Some(procs[row].pid);
+            self.row.set(row);
+            self.exited.set(false);
+        }
+    }
+}
+
+/// Scroll position in a list view.
+///
+/// Like [ProcessSelection], the list length and page size are updated when
+/// the list is rendered.
+#[derive(Debug, Default)]
+pub struct ScrollPosition {
+    offset: Cell<usize>,
+    len: Cell<usize>,
+    page: Cell<usize>,
+}
+
+impl ScrollPosition {
+    /// Update the list length and page size, returning the clamped offset.
+    pub fn sync(&self, len: usize, page: usize) -> usize {
+        self.len.set(len);
+        self.page.set(page);
+        let offset = self.offset.get().min(len.saturating_sub(page));
+        self.offset.set(offset);
+        offset
+    }
+
+    /// Scroll by a number of rows.
+    pub fn scroll_by(&self, delta: isize) {
+        let max = self.len.get().saturating_sub(self.page.get());
+        let offset = self.offset.get().saturating_add_signed(delta).min(max);
+        self.offset.set(offset);
+    }
+
+    /// Scroll by a number of pages.
+    pub fn scroll_pages(&self, pages: isize) {
+        let page = self.page.get().max(1) as isize;
+        self.scroll_by(pages * page);
+    }
+
+    /// Scroll to the top of the list.
+    pub fn home(&self) {
+        self.offset.set(0);
+    }
+
+    /// Scroll to the end of the list.
+    pub fn end(&self) {
+        self.offset
+            .set(self.len.get().saturating_sub(self.page.get()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn procs(n: u32) -> Vec<Process> {
+        (0..n).map(|pid| Process::test(pid, None, "test")).collect()
+    }
+
+    fn selected(pid: u32) -> ProcessSelection {
+        ProcessSelection {
+            pid: Some(pid),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sync_scrolls_to_selection() {
+        let procs = procs(20);
+        let sel = selected(12);
+        assert_eq!(sel.sync(&procs, 5, 0), Some(12));
+        assert_eq!(sel.offset(), 8);
+        assert_eq!(sel.row_index(4), 12);
+
+        // scrolling back up keeps the selection at the top
+        let sel = ProcessSelection {
+            pid: Some(3),
+            ..sel
+        };
+        assert_eq!(sel.sync(&procs, 5, 0), Some(3));
+        assert_eq!(sel.offset(), 3);
+    }
+
+    #[test]
+    fn test_sync_clamps_offset() {
+        let sel = selected(19);
+        sel.sync(&procs(20), 5, 0);
+        assert_eq!(sel.offset(), 15);
+        // the list shrinks, so the offset is pulled back to fill the page
+        assert_eq!(sel.sync(&procs(10), 5, 0), None);
+        assert!(sel.exited());
+        assert_eq!(sel.offset(), 5);
+    }
+
+    #[test]
+    fn test_move() {
+        let procs = procs(20);
+        let mut sel = ProcessSelection::default();
+        sel.move_by(&procs, 3);
+        assert_eq!(sel.pid, Some(0));
+        sel.move_by(&procs, 3);
+        assert_eq!(sel.pid, Some(3));
+        sel.move_by(&procs, -10);
+        assert_eq!(sel.pid, Some(0));
+        sel.sync(&procs, 5, 0);
+        sel.move_pages(&procs, 10);
+        assert_eq!(sel.pid, Some(19));
+    }
+
+    #[test]
+    fn test_move_from_exited() {
+        let mut sel = selected(4);
+        sel.sync(&procs(10), 5, 0);
+        // the selected process exits, so moves continue from its old row
+        let remaining: Vec<_> = procs(10).into_iter().filter(|p| p.pid != 4).collect();
+        sel.move_by(&remaining, 1);
+        assert_eq!(sel.pid, Some(6));
+    }
+
+    #[test]
+    fn test_sync_with_pinned_rows() {
+        let procs = procs(20);
+        // two pinned rows leave three scrolling rows
+        let sel = selected(12);
+        assert_eq!(sel.sync(&procs, 5, 2), Some(12));
+        assert_eq!(sel.offset(), 8);
+        assert_eq!(sel.row_index(0), 0);
+        assert_eq!(sel.row_index(1), 1);
+        assert_eq!(sel.row_index(2), 10);
+        assert_eq!(sel.row_index(4), 12);
+
+        // selecting a pinned row doesn't scroll
+        let sel = ProcessSelection {
+            pid: Some(1),
+            ..sel
+        };
+        assert_eq!(sel.sync(&procs, 5, 2), Some(1));
+        assert_eq!(sel.offset(), 8);
+
+        let sel = ProcessSelection {
+            pid: Some(19),
+            ..sel
+        };
+        sel.sync(&procs, 5, 2);
+        assert_eq!(sel.offset(), 15);
+        assert_eq!(sel.row_index(4), 19);
+    }
+
+    #[test]
+    fn test_sync_all_pinned() {
+        let sel = selected(3);
+        assert_eq!(sel.sync(&procs(4), 5, 4), Some(3));
+        assert_eq!(sel.offset(), 0);
+        assert_eq!(sel.row_index(3), 3);
+    }
+}
@@ -19,6 +19,9 @@ pub trait SystemResources {
     /// Get CPU utilization.
     fn global_cpu(&self) -> Result<CPU>;
 
+    /// Get the utilization of each logical CPU.
+    fn cpus(&self) -> Result<Vec<CPU>>;
+
     /// Get memory usage.
     fn memory(&self) -> Result<Memory>;
 
@@ -30,14 +33,66 @@ pub trait SystemResources {
 
     /// Get pressure stall info.
     fn pressure(&self) -> Result<SystemPressure>;
+
+    /// Get the processes killed by the OOM killer since startup.
+    fn oom_kills(&self) -> Result<OomKills>;
 }
 
 pub trait RunningProcesses {
     /// Get the running processes.
     fn processes(&self) -> Result<ProcessList>;
 
+    /// Get the rates at which processes are starting and exiting.
+    fn process_churn(&self) -> Result<ProcessChurn>;
+
     /// Get command information for a process.
     fn process_cmd_info(&self, pid: u32) -> Result<ProcessCommandInfo>;
+
+    /// Get detailed memory usage for a process.
+    fn process_memory(&self, pid: u32) -> Result<ProcessMemory>;
+
+    /// Get the memory mappings of a process.
+    fn process_memory_maps(&self, pid: u32) -> Result<Vec<MemoryMapping>>;
+
+    /// Get the OOM killer scores for a process.
+    fn process_oom(&self, pid: u32) -> Result<ProcessOom>;
+
+    /// Get the context switches per second of a process.
+    fn process_ctx_switches(&self, pid: u32) -> Result<ContextSwitches>;
+
+    /// Get the fraction of time a process spent waiting for a CPU since the last
+    /// refresh.
+    fn process_sched_wait(&self, pid: u32) -> Result<f32>;
+
+    /// Get the open files of a process.
+    fn process_open_files(&self, pid: u32) -> Result<Vec<OpenFile>>;
+
+    /// Get the environment variables of a process, sorted by name.
+    fn process_environ(&self, pid: u32) -> Result<Vec<(String, String)>>;
+
+    /// Get miscellaneous details (working directory, cgroup, etc.) for a process.
+    fn process_details(&self, pid: u32) -> Result<ProcessDetails>;
+
+    /// Get the file descriptor usage and limits for a process.
+    fn process_fd_usage(&self, pid: u32) -> Result<ProcessFdUsage>;
+
+    /// Get the I/O scheduling priority of a process.
+    fn process_io_priority(&self, pid: u32) -> Result<IOPriority>;
+
+    /// Get the CPUs a process may run on.
+    fn process_affinity(&self, pid: u32) -> Result<Vec<u32>>;
+
+    /// Send a signal to a process.
+    fn signal_process(&self, pid: u32, signal: ProcessSignal) -> Result<()>;
+
+    /// Set the nice value of a process.
+    fn set_process_nice(&self, pid: u32, nice: i32) -> Result<()>;
+
+    /// Set the I/O scheduling priority of a process.
+    fn set_process_io_priority(&self, pid: u32, prio: IOPriority) -> Result<()>;
+
+    /// Set the CPUs a process may run on.
+    fn set_process_affinity(&self, pid: u32, cpus: &[u32]) -> Result<()>;
 }
 
 pub trait NetworkInfo {
@@ -1,4 +1,7 @@
 //! Monitor state.
+use std::collections::{HashMap, HashSet};
+use std::time::{Instant, SystemTime};
+
 use crate::backend::{BackendResult, MonitorBackend};
 
 use super::{process::ProcessList, *};
@@ -8,26 +11,120 @@ pub struct MonitorState<'back> {
     pub options: Options,
     /// Sort order for processes.  [None] to sort automatically.
     pub proc_sort: Option<ProcSortOrder>,
+    /// Whether to reverse the process sort order.
+    pub proc_sort_reverse: bool,
+    /// Processes whose children are collapsed in the process tree.
+    pub proc_collapsed: HashSet<u32>,
+    /// Selected process in the process table.
+    pub selection: ProcessSelection,
+    /// Scroll position of the network, disk & filesystem tables.
+    pub table_scroll: ScrollPosition,
+    /// Processes tagged for bulk actions, with their start times so a reused PID
+    /// is not acted on.
+    pub tagged: HashMap<u32, Option<SystemTime>>,
+    /// Status message reporting the result of the last action.
+    pub status: Option<StatusMessage>,
+    /// Number of times the backend data has been refreshed.
+    pub updates: u64,
 
     pub backend: &'back mut dyn MonitorBackend,
     pub user_db: UsersCache,
 }
 
+/// Message reporting the result of a user action.
+#[derive(Debug, Clone)]
+pub struct StatusMessage {
+    pub text: String,
+    /// Whether the message reports an error.
+    pub error: bool,
+    pub time: Instant,
+}
+
 impl<'back> MonitorState<'back> {
     pub fn create(
         options: Options,
         backend: &'back mut dyn MonitorBackend,
     ) -> Result<MonitorState<'back>> {
+        backend.set_cpu_mode(options.cpu_mode);
         Ok(MonitorState {
             options,
             backend,
             proc_sort: None,
+            proc_sort_reverse: false,
+            proc_collapsed: HashSet::new(),
+            selection: ProcessSelection::default(),
+            table_scroll: ScrollPosition::default(),
+            tagged: HashMap::new(),
+            status: None,
+            updates: 0,
             user_db: UsersCache::new(),
         })
     }
 
     pub fn refresh(&mut self) -> BackendResult<()> {
-        self.backend.update(&self.options)
+        self.backend.update(&self.options).map(|()| {
+            self.updates += 1;
+            self.check_marked_processes();
+        })
+    }
+
+    /// Query whether a process is tagged.
+    pub fn is_tagged(&self, proc: &Process) -> bool {
+        self.tagged.get(&proc.pid) == Some(&proc.start_time)
+    }
+
+    /// Untag processes that have exited, look up the start times of processes
+    /// pinned by PID on the command line, and unpin the PIDs that do not exist.
+    fn check_marked_processes(&mut self) {
+        let resolve = self.options.pin_pids.values().any(Option::is_none);
+        if !resolve && self.tagged.is_empty() {
+            return;
+        }
+        let Some(procs) = self.listed_processes().ok() else {
+            return;
+        };
+        let starts: HashMap<u32, Option<SystemTime>> = procs
+            .iter()
+            .filter(|p| p.change != ProcessChange::Exited)
+            .map(|p| (p.pid, p.start_time))
+            .collect();
+        self.tagged
+            .retain(|pid, start| starts.get(pid) == Some(start));
+        self.options
+            .pin_pids
+            .retain(|pid, start| match (*start, starts.get(pid)) {
+                (Some(_), _) => true,
+                (None, Some(cur)) => {
+                    *start = *cur;
+                    true
+                }
+                (None, None) => false,
+            });
+    }
+
+    /// Get the processes, or threads, listed in the current thread mode.
+    fn listed_processes(&self) -> BackendResult<Vec<Process>> {
+        match self.options.threads {
+            ThreadMode::Processes => self.backend.processes(),
+            ThreadMode::AllThreads => self.backend.threads(),
+            ThreadMode::ProcessThreads(pid) => self.backend.process_threads(pid),
+        }
+    }
+
+    /// Set the scale of process CPU percentages.  The backend applies it right away,
+    /// so the displayed values match the column header without waiting to refresh.
+    pub fn set_cpu_mode(&mut self, mode: CpuPctMode) {
+        self.options.cpu_mode = mode;
+        self.backend.set_cpu_mode(mode);
+    }
+
+    /// Set the status message.
+    pub fn set_status<S: Into<String>>(&mut self, text: S, error: bool) {
+        self.status = Some(StatusMessage {
+            text: text.into(),
+            error,
+            time: Instant::now(),
+        });
     }
 }
 
@@ -36,10 +133,19 @@ impl<'back> MonitorData for MonitorState<'back> {
         self.backend
     }
 
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
     fn lookup_user(&self, uid: u32) -> Result<Option<String>> {
         let u = self.user_db.get_user_by_uid(uid);
         Ok(u.map(|u| u.name().to_string_lossy().to_string()))
     }
+
+    fn lookup_group(&self, gid: u32) -> Result<Option<String>> {
+        let g = self.user_db.get_group_by_gid(gid);
+        Ok(g.map(|g| g.name().to_string_lossy().to_string()))
+    }
 }
 
 impl<'back> SystemInfo for MonitorState<'back> {
@@ -65,6 +171,10 @@ impl<'back> SystemResources for MonitorState<'back> {
         self.backend.global_cpu()
     }
 
+    fn cpus(&self) -> BackendResult<Vec<CPU>> {
+        self.backend.cpus()
+    }
+
     fn memory(&self) -> BackendResult<Memory> {
         self.backend.memory()
     }
@@ -80,16 +190,84 @@ impl<'back> SystemResources for MonitorState<'back> {
     fn pressure(&self) -> BackendResult<SystemPressure> {
         self.backend.pressure()
     }
+
+    fn oom_kills(&self) -> BackendResult<OomKills> {
+        self.backend.oom_kills()
+    }
 }
 
 impl<'back> RunningProcesses for MonitorState<'back> {
     fn processes(&self) -> BackendResult<ProcessList> {
-        ProcessList::create(self, self.backend.processes()?)
+        ProcessList::create(self, self.listed_processes()?)
+    }
+
+    fn process_churn(&self) -> BackendResult<ProcessChurn> {
+        self.backend.process_churn()
     }
 
     fn process_cmd_info(&self, pid: u32) -> BackendResult<ProcessCommandInfo> {
         self.backend.process_cmd_info(pid)
     }
+
+    fn process_memory(&self, pid: u32) -> BackendResult<ProcessMemory> {
+        self.backend.process_memory(pid)
+    }
+
+    fn process_memory_maps(&self, pid: u32) -> BackendResult<Vec<MemoryMapping>> {
+        self.backend.process_memory_maps(pid)
+    }
+
+    fn process_oom(&self, pid: u32) -> BackendResult<ProcessOom> {
+        self.backend.process_oom(pid)
+    }
+
+    fn process_ctx_switches(&self, pid: u32) -> BackendResult<ContextSwitches> {
+        self.backend.process_ctx_switches(pid)
+    }
+
+    fn process_sched_wait(&self, pid: u32) -> BackendResult<f32> {
+        self.backend.process_sched_wait(pid)
+    }
+
+    fn process_open_files(&self, pid: u32) -> BackendResult<Vec<OpenFile>> {
+        self.backend.process_open_files(pid)
+    }
+
+    fn process_environ(&self, pid: u32) -> BackendResult<Vec<(String, String)>> {
+        self.backend.process_environ(pid)
+    }
+
+    fn process_details(&self, pid: u32) -> BackendResult<ProcessDetails> {
+        self.backend.process_details(pid)
+    }
+
+    fn process_fd_usage(&self, pid: u32) -> BackendResult<ProcessFdUsage> {
+        self.backend.process_fd_usage(pid)
+    }
+
+    fn process_io_priority(&self, pid: u32) -> BackendResult<IOPriority> {
+        self.backend.process_io_priority(pid)
+    }
+
+    fn process_affinity(&self, pid: u32) -> BackendResult<Vec<u32>> {
+        self.backend.process_affinity(pid)
+    }
+
+    fn signal_process(&self, pid: u32, signal: ProcessSignal) -> BackendResult<()> {
+        self.backend.signal_process(pid, signal)
+    }
+
+    fn set_process_nice(&self, pid: u32, nice: i32) -> BackendResult<()> {
+        self.backend.set_process_nice(pid, nice)
+    }
+
+    fn set_process_io_priority(&self, pid: u32, prio: IOPriority) -> BackendResult<()> {
+        self.backend.set_process_io_priority(pid, prio)
+    }
+
+    fn set_process_affinity(&self, pid: u32, cpus: &[u32]) -> BackendResult<()> {
+        self.backend.set_process_affinity(pid, cpus)
+    }
 }
 
 impl<'back> NetworkInfo for MonitorState<'back> {
@@ -0,0 +1,232 @@
+//! Process tree ordering.
+use std::collections::{HashMap, HashSet};
+
+use super::Process;
+
+/// A process's position in the process tree.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessTreeNode {
+    /// Depth of the process in the tree (0 for roots).
+    pub depth: usize,
+    /// Indentation glyphs to display before the command.
+    pub prefix: String,
+    /// Number of direct children.
+    pub children: usize,
+    /// Whether the process's children are collapsed (hidden).
+    pub collapsed: bool,
+    /// CPU utilization of the process and all its descendants.
+    pub cpu_util: f32,
+    /// Memory utilization of the process and all its descendants.
+    pub mem_util: f32,
+}
+
+struct TreeBuilder<'a> {
+    slots: Vec<Option<Process>>,
+    children: Vec<Vec<usize>>,
+    totals: Vec<(f32, f32)>,
+    collapsed: &'a HashSet<u32>,
+    output: Vec<Process>,
+}
+
+/// Order processes as a tree, keeping the existing order among siblings.
+///
+/// Descendants of processes in `collapsed` are omitted from the result.
+pub(super) fn tree_order(procs: Vec<Process>, collapsed: &HashSet<u32>) -> Vec<Process> {
+    let n = procs.len();
+    let index: HashMap<u32, usize> = procs.iter().enumerate().map(|(i, p)| (p.pid, i)).collect();
+    let mut children = vec![Vec::new(); n];
+    let mut roots = Vec::new();
+    for (i, proc) in procs.iter().enumerate() {
+        match proc.ppid.and_then(|pp| index.get(&pp)) {
+            Some(&parent) if parent != i => children[parent].push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    let mut builder = TreeBuilder {
+        totals: procs.iter().map(|p| (p.cpu_util, p.mem_util)).collect(),
+        slots: procs.into_iter().map(Some).collect(),
+        children,
+        collapsed,
+        output: Vec::with_capacity(n),
+    };
+    let mut done = vec![false; n];
+    for &root in &roots {
+        builder.sum_totals(root, &mut done);
+    }
+    for root in roots {
+        builder.emit(root, 0, "", true);
+    }
+    // anything left over is in a parent cycle (e.g. from PID reuse mid-scan)
+    for i in 0..n {
+        builder.emit(i, 0, "", true);
+    }
+    builder.output
+}
+
+impl TreeBuilder<'_> {
+    /// Accumulate subtree totals in post-order.
+    fn sum_totals(&mut self, node: usize, done: &mut [bool]) -> (f32, f32) {
+        done[node] = true;
+        let mut total = self.totals[node];
+        for i in 0..self.children[node].len() {
+            let child = self.children[node][i];
+            if !done[child] {
+                let (cpu, mem) = self.sum_totals(child, done);
+                total.0 += cpu;
+                total.1 += mem;
+            }
+        }
+        self.totals[node] = total;
+        total
+    }
+
+    /// Emit a process and its (expanded) descendants in display order.
+    ///
+    /// `indent` is the continuation glyphs from the process's ancestors, and
+    /// `last` is whether it is the last of its siblings.
+    fn emit(&mut self, node: usize, depth: usize, indent: &str, last: bool) {
+        let Some(mut proc) = self.slots[node].take() else {
+            // already emitted (only possible with inconsistent parent links)
+            return;
+        };
+        let children = std::mem::take(&mut self.children[node]);
+        let collapsed = !children.is_empty() && self.collapsed.contains(&proc.pid);
+        let (branch, cont) = match (depth, last) {
+            (0, _) => ("", ""),
+            (_, true) => ("└─", "  "),
+            (_, false) => ("├─", "│ "),
+        };
+        let marker = match (collapsed, depth) {
+            (true, _) => "+ ",
+            (false, 0) => "",
+            (false, _) => " ",
+        };
+        let (cpu_util, mem_util) = self.totals[node];
+        proc.tree = Some(ProcessTreeNode {
+            depth,
+            prefix: format!("{}{}{}", indent, branch, marker),
+            children: children.len(),
+            collapsed,
+            cpu_util,
+            mem_util,
+        });
+        self.output.push(proc);
+
+        if collapsed {
+            for child in children {
+                self.discard(child);
+            }
+            return;
+        }
+        let indent = format!("{}{}", indent, cont);
+        let n = children.len();
+        for (i, child) in children.into_iter().enumerate() {
+            self.emit(child, depth + 1, &indent, i + 1 == n);
+        }
+    }
+
+    /// Drop a hidden process and its descendants, so they aren't emitted as
+    /// leftovers.
+    fn discard(&mut self, node: usize) {
+        if self.slots[node].take().is_some() {
+            for child in std::mem::take(&mut self.children[node]) {
+                self.discard(child);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, ppid: Option<u32>, cpu_util: f32) -> Process {
+        Process {
+            cpu_util,
+            ..Process::test(pid, ppid, "test")
+        }
+    }
+
+    /// Order processes, getting each one's PID and tree prefix.
+    fn order(procs: Vec<Process>, collapsed: &[u32]) -> Vec<(u32, String)> {
+        let collapsed = collapsed.iter().copied().collect();
+        tree_order(procs, &collapsed)
+            .into_iter()
+            .map(|p| (p.pid, p.tree.unwrap().prefix))
+            .collect()
+    }
+
+    fn rows(rows: &[(u32, &str)]) -> Vec<(u32, String)> {
+        rows.iter().map(|(p, s)| (*p, s.to_string())).collect()
+    }
+
+    #[test]
+    fn test_tree_order() {
+        let procs = vec![
+            process(4, Some(2), 0.0),
+            process(1, None, 0.0),
+            process(3, Some(1), 0.0),
+            process(2, Some(1), 0.0),
+            process(5, Some(3), 0.0),
+        ];
+        assert_eq!(
+            order(procs, &[]),
+            rows(&[(1, ""), (3, "├─ "), (5, "│ └─ "), (2, "└─ "), (4, "  └─ ")])
+        );
+    }
+
+    #[test]
+    fn test_orphans() {
+        // parents that aren't listed (e.g. filtered out) make their children roots
+        let procs = vec![
+            process(7, Some(99), 0.0),
+            process(8, Some(7), 0.0),
+            process(9, None, 0.0),
+        ];
+        assert_eq!(order(procs, &[]), rows(&[(7, ""), (8, "└─ "), (9, "")]));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let procs = vec![
+            process(1, Some(1), 0.0),
+            process(2, Some(3), 0.0),
+            process(3, Some(2), 0.0),
+            process(4, Some(3), 0.0),
+        ];
+        let order = order(procs, &[]);
+        let mut pids: Vec<u32> = order.iter().map(|(p, _)| *p).collect();
+        pids.sort();
+        assert_eq!(pids, vec![1, 2, 3, 4]);
+        assert_eq!(order[0], (1, String::new()));
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let procs = vec![
+            process(1, None, 1.0),
+            process(2, Some(1), 2.0),
+            process(3, Some(2), 4.0),
+            process(4, Some(1), 8.0),
+        ];
+        let tree = tree_order(procs, &[2].into());
+        let nodes: Vec<_> = tree
+            .iter()
+            .map(|p| (p.pid, p.tree.clone().unwrap()))
+            .collect();
+        assert_eq!(
+            nodes
+                .iter()
+                .map(|(p, n)| (*p, n.prefix.clone()))
+                .collect::<Vec<_>>(),
+            rows(&[(1, ""), (2, "├─+ "), (4, "└─ ")])
+        );
+        let (_, collapsed) = &nodes[1];
+        assert!(collapsed.collapsed);
+        assert_eq!(collapsed.children, 1);
+        // totals still include hidden descendants
+        assert_eq!(collapsed.cpu_util, 6.0);
+        assert_eq!(nodes[0].1.cpu_util, 15.0);
+    }
+}
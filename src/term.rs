@@ -2,11 +2,12 @@
 use std::{
     backtrace::{Backtrace, BacktraceStatus},
     io::{stdout, Stdout},
-    panic::{self, PanicInfo},
+    panic::{self, PanicHookInfo},
 };
 
 use anyhow::Result;
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
@@ -14,7 +15,8 @@ use log::*;
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 #[allow(unused_must_use)]
-fn handle_panic(pi: &PanicInfo<'_>) {
+fn handle_panic(pi: &PanicHookInfo<'_>) {
+    stdout().execute(DisableMouseCapture);
     stdout().execute(LeaveAlternateScreen);
     disable_raw_mode();
     eprintln!("{}", pi);
@@ -24,19 +26,25 @@ fn handle_panic(pi: &PanicInfo<'_>) {
     }
 }
 
-pub fn with_terminal<F, T>(func: F) -> Result<T>
+pub fn with_terminal<F, T>(mouse: bool, func: F) -> Result<T>
 where
     F: FnOnce(&mut Terminal<CrosstermBackend<Stdout>>) -> Result<T>,
 {
     panic::set_hook(Box::new(handle_panic));
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
+    if mouse {
+        stdout().execute(EnableMouseCapture)?;
+    }
 
     let backend = CrosstermBackend::new(stdout());
     let mut term = Terminal::new(backend)?;
 
     let res = func(&mut term);
 
+    if mouse {
+        stdout().execute(DisableMouseCapture)?;
+    }
     stdout().execute(LeaveAlternateScreen)?;
     disable_raw_mode()?;
 
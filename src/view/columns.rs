@@ -0,0 +1,45 @@
+//! Process column chooser.
+
+use anyhow::Result;
+use ratatui::layout::Alignment;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{Block, ListItem, Padding};
+use ratatui::Frame;
+
+use crate::model::MonitorState;
+
+use super::{optional_process_columns, popup::render_list_popup};
+
+pub fn render_column_chooser<'b>(
+    frame: &mut Frame,
+    state: &MonitorState<'b>,
+    cursor: usize,
+) -> Result<()> {
+    let block = Block::bordered()
+        .title("Columns")
+        .title_style(Style::new().fg(Color::Blue))
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+
+    let mut lines = Vec::<ListItem>::new();
+    for (i, (label, desc)) in optional_process_columns().into_iter().enumerate() {
+        let mark = if state.options.proc_columns.contains(label) {
+            "x"
+        } else {
+            " "
+        };
+        let item = ListItem::new(format!("[{}] {:<6} {}", mark, label, desc));
+        lines.push(if i == cursor {
+            item.style(Style::new().reversed())
+        } else {
+            item
+        });
+    }
+    lines.push(ListItem::new(""));
+    lines.push(ListItem::new("space to toggle, esc to close").style(Style::new().italic()));
+
+    render_list_popup(frame, lines, block);
+
+    Ok(())
+}
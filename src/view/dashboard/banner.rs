@@ -1,9 +1,19 @@
+use std::time::{Duration, SystemTime};
+
 use anyhow::Result;
-use chrono::Local;
+use chrono::{DateTime, Local};
 use friendly::duration;
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use crate::model::MonitorData;
+use crate::{
+    backend::error::BackendErrorFilter,
+    model::{MonitorData, MonitorState, SystemResources},
+};
+
+/// How long to flash a notice after the event it reports.
+const NOTICE_FLASH: Duration = Duration::from_secs(60);
+/// How long to display action status messages.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub fn render_banner(frame: &mut Frame, state: &dyn MonitorData, area: Rect) -> Result<()> {
     let layout = Layout::new(
@@ -39,3 +49,69 @@ pub fn render_banner(frame: &mut Frame, state: &dyn MonitorData, area: Rect) ->
 
     Ok(())
 }
+
+/// Render notices of important system events (currently OOM kills) and the
+/// status of the last user action.
+pub fn render_notices(frame: &mut Frame, state: &MonitorState<'_>, area: Rect) -> Result<()> {
+    let status = state
+        .status
+        .as_ref()
+        .filter(|s| s.time.elapsed() < STATUS_TIMEOUT);
+    let oom = state
+        .oom_kills()
+        .acceptable_to_opt()?
+        .filter(|oom| oom.count > 0);
+
+    // the status and the OOM notice share the line when both are shown
+    let (status_area, oom_area) = match (status, &oom) {
+        (Some(_), Some(_)) => {
+            let [left, right] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+            (left, right)
+        }
+        _ => (area, area),
+    };
+
+    if let Some(status) = status {
+        let style = if status.error {
+            Style::new().fg(Color::Red)
+        } else {
+            Style::new().fg(Color::Green)
+        };
+        let status = Paragraph::new(Line::from(Span::styled(status.text.as_str(), style)));
+        frame.render_widget(status, status_area);
+    }
+
+    let oom = match oom {
+        Some(oom) => oom,
+        None => return Ok(()),
+    };
+
+    let mut text = format!(
+        "OOM killer: {} {} killed since start",
+        oom.count,
+        if oom.count == 1 {
+            "process"
+        } else {
+            "processes"
+        }
+    );
+    let mut style = Style::new().fg(Color::Red).bold();
+    if let Some(last) = oom.last {
+        let time: DateTime<Local> = last.into();
+        text.push_str(&time.format(", last at %H:%M:%S").to_string());
+        let age = SystemTime::now()
+            .duration_since(last)
+            .unwrap_or(Duration::ZERO);
+        // we redraw every second, so alternating on even seconds flashes
+        if age < NOTICE_FLASH && Local::now().timestamp() % 2 == 0 {
+            style = style.reversed();
+        }
+    }
+
+    let notice =
+        Paragraph::new(vec![Line::from(Span::styled(text, style))]).alignment(Alignment::Center);
+    frame.render_widget(notice, oom_area);
+
+    Ok(())
+}
@@ -0,0 +1,305 @@
+//! Compact command line display.
+//!
+//! Interpreted programs all look alike in a full command line (`/usr/bin/python3 -u
+//! /very/long/path/train.py ...`), so the compact form drops interpreter options and
+//! directory prefixes, and highlights the program or script being run.
+
+use ratatui::prelude::*;
+
+/// Paths longer than this have their directories stripped.
+const LONG_PATH: usize = 24;
+
+/// Command line conventions of an interpreter.
+struct Interpreter {
+    /// Base names of the interpreter program (version suffixes are allowed).
+    names: &'static [&'static str],
+    /// Options that take the next argument as their value.
+    arg_opts: &'static [&'static str],
+    /// Options whose value is the module or archive to run.
+    target_opts: &'static [&'static str],
+    /// Options that run code given on the command line.
+    code_opts: &'static [&'static str],
+}
+
+static INTERPRETERS: &[Interpreter] = &[
+    Interpreter {
+        names: &["python", "pypy"],
+        arg_opts: &["-W", "-X", "-Q"],
+        target_opts: &["-m"],
+        code_opts: &["-c"],
+    },
+    Interpreter {
+        names: &["node", "nodejs"],
+        arg_opts: &[
+            "-r",
+            "--require",
+            "--import",
+            "--loader",
+            "--experimental-loader",
+            "-C",
+            "--conditions",
+        ],
+        target_opts: &[],
+        code_opts: &["-e", "--eval", "-p", "--print"],
+    },
+    Interpreter {
+        names: &["java"],
+        arg_opts: &[
+            "-cp",
+            "-classpath",
+            "--class-path",
+            "-p",
+            "--module-path",
+            "--upgrade-module-path",
+            "--add-modules",
+            "--add-opens",
+            "--add-exports",
+            "--add-reads",
+            "--limit-modules",
+            "--patch-module",
+        ],
+        target_opts: &["-jar", "-m", "--module"],
+        code_opts: &[],
+    },
+    Interpreter {
+        names: &["ruby"],
+        arg_opts: &["-I", "-r", "-C", "-E", "-F"],
+        target_opts: &[],
+        code_opts: &["-e"],
+    },
+    Interpreter {
+        names: &["perl"],
+        arg_opts: &["-I", "-M", "-m"],
+        target_opts: &[],
+        code_opts: &["-e", "-E"],
+    },
+    Interpreter {
+        names: &["bash", "sh", "dash", "zsh"],
+        arg_opts: &["-o", "+o", "-O", "+O", "--rcfile", "--init-file"],
+        target_opts: &[],
+        code_opts: &["-c"],
+    },
+    Interpreter {
+        names: &["R", "Rscript"],
+        arg_opts: &["--encoding"],
+        target_opts: &["-f", "--file"],
+        code_opts: &["-e"],
+    },
+];
+
+/// Format a command line in compact form.
+pub fn compact_command(cmdline: &[String]) -> Line<'static> {
+    let highlight = Style::new().bold();
+    let mut args: Vec<&str> = cmdline.iter().map(|s| s.as_str()).collect();
+    if args.is_empty() {
+        return Line::default();
+    }
+    // some programs rewrite their command line into a single string
+    if args.len() == 1 && args[0].contains(char::is_whitespace) {
+        args = args[0].split_whitespace().collect();
+    }
+
+    let program = base_name(args[0]);
+    let mut spans = Vec::with_capacity(args.len() * 2);
+    let rest = match INTERPRETERS.iter().find(|i| is_interpreter(i, program)) {
+        Some(interp) => {
+            spans.push(Span::raw(program.to_string()));
+            let (target, rest) = interpreted_target(interp, &args[1..]);
+            match target {
+                Target::Script(script) => {
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(base_name(script).to_string(), highlight));
+                }
+                Target::Module(opt, module) => {
+                    spans.push(Span::raw(format!(" {} ", opt)));
+                    spans.push(Span::styled(base_name(module).to_string(), highlight));
+                }
+                Target::Code(opt) => spans.push(Span::raw(format!(" {}", opt))),
+                Target::None => (),
+            }
+            rest
+        }
+        None => {
+            spans.push(Span::styled(program.to_string(), highlight));
+            &args[1..]
+        }
+    };
+
+    for arg in rest {
+        spans.push(Span::raw(" "));
+        spans.push(Span::raw(short_arg(arg)));
+    }
+    Line::from(spans)
+}
+
+/// What an interpreter has been asked to run.
+enum Target<'a> {
+    /// A script file.
+    Script(&'a str),
+    /// A module or archive, with the option that selected it.
+    Module(&'a str, &'a str),
+    /// Code from the command line, with the option that passed it.
+    Code(&'a str),
+    /// Nothing (e.g. an interactive session).
+    None,
+}
+
+/// Find the target of an interpreter's arguments, returning it along with the
+/// arguments passed to it.
+fn interpreted_target<'a, 'c>(
+    interp: &Interpreter,
+    args: &'c [&'a str],
+) -> (Target<'a>, &'c [&'a str]) {
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i];
+        if arg == "--" {
+            return match args.get(i + 1) {
+                Some(script) => (Target::Script(script), &args[i + 2..]),
+                None => (Target::None, &[]),
+            };
+        } else if interp.target_opts.contains(&arg) {
+            return match args.get(i + 1) {
+                Some(module) => (Target::Module(arg, module), &args[i + 2..]),
+                None => (Target::None, &[]),
+            };
+        } else if let Some((opt, module)) = option_value(arg) {
+            if interp.target_opts.contains(&opt) {
+                return (Target::Module(opt, module), &args[i + 1..]);
+            }
+        } else if interp.code_opts.contains(&arg) {
+            return (Target::Code(arg), &args[i + 1..]);
+        } else if interp.arg_opts.contains(&arg) {
+            i += 1;
+        } else if !arg.starts_with('-') && !arg.starts_with('+') {
+            return (Target::Script(arg), &args[i + 1..]);
+        }
+        i += 1;
+    }
+    (Target::None, &[])
+}
+
+/// Split an `--option=value` argument.
+fn option_value(arg: &str) -> Option<(&str, &str)> {
+    arg.split_once('=').filter(|(opt, _)| opt.starts_with('-'))
+}
+
+/// Check whether a program name is an interpreter, allowing version suffixes
+/// such as `python3.11`.
+fn is_interpreter(interp: &Interpreter, program: &str) -> bool {
+    interp.names.iter().any(|name| {
+        program.strip_prefix(name).is_some_and(|suffix| {
+            suffix
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+        })
+    })
+}
+
+/// Get the last component of a path.
+fn base_name(path: &str) -> &str {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some((_, name)) if !name.is_empty() => name,
+        _ => path,
+    }
+}
+
+/// Shorten an argument by stripping the directories from long paths, including
+/// paths given as `--option=/some/path`.
+fn short_arg(arg: &str) -> String {
+    match option_value(arg) {
+        Some((opt, value)) => format!("{}={}", opt, short_path(value)),
+        None => short_path(arg),
+    }
+}
+
+fn short_path(path: &str) -> String {
+    // arguments with spaces are usually code (`sh -c ...`), not paths
+    let is_path = path.starts_with(['/', '~', '.']) && path.contains('/') && !path.contains(' ');
+    if path.len() > LONG_PATH && is_path {
+        format!("…/{}", base_name(path))
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    /// Get the compact text and its highlighted part.
+    fn compact(cmdline: &[&str]) -> (String, Option<String>) {
+        let line = compact_command(&args(cmdline));
+        let highlight = line
+            .spans
+            .iter()
+            .find(|s| s.style.add_modifier.contains(Modifier::BOLD))
+            .map(|s| s.content.to_string());
+        (line.to_string(), highlight)
+    }
+
+    #[test]
+    fn test_plain_program() {
+        assert_eq!(
+            compact(&["/usr/bin/cat", "/home/user/some/long/directory/notes.txt"]),
+            ("cat …/notes.txt".into(), Some("cat".into()))
+        );
+    }
+
+    #[test]
+    fn test_python_script() {
+        assert_eq!(
+            compact(&["/usr/bin/python3", "-u", "/x/train.py", "--lr", "0.1"]),
+            ("python3 train.py --lr 0.1".into(), Some("train.py".into()))
+        );
+    }
+
+    #[test]
+    fn test_rewritten_command_line() {
+        assert_eq!(
+            compact(&["python3 -u /x/train.py --lr 0.1"]),
+            ("python3 train.py --lr 0.1".into(), Some("train.py".into()))
+        );
+        assert_eq!(
+            compact(&["sshd: user@pts/0"]),
+            ("sshd: user@pts/0".into(), Some("sshd:".into()))
+        );
+    }
+
+    #[test]
+    fn test_interpreter_options() {
+        assert_eq!(
+            compact(&["python3.11", "-X", "dev", "-m", "http.server", "8000"]),
+            (
+                "python3.11 -m http.server 8000".into(),
+                Some("http.server".into())
+            )
+        );
+        assert_eq!(
+            compact(&["java", "-cp", "lib.jar", "-jar", "/opt/app/server.jar"]),
+            ("java -jar server.jar".into(), Some("server.jar".into()))
+        );
+        assert_eq!(
+            compact(&["node", "--import=./hooks.mjs", "/srv/app/index.js"]),
+            ("node index.js".into(), Some("index.js".into()))
+        );
+    }
+
+    #[test]
+    fn test_interpreter_code() {
+        assert_eq!(
+            compact(&["/bin/sh", "-c", "sleep 10 && echo done"]),
+            ("sh -c sleep 10 && echo done".into(), None)
+        );
+        assert_eq!(compact(&["python3"]), ("python3".into(), None));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(compact(&[]), (String::new(), None));
+    }
+}
@@ -6,7 +6,7 @@ use ratatui::prelude::*;
 
 use crate::{
     backend::error::BackendErrorFilter,
-    model::MonitorData,
+    model::{IOCounterMode, IOTotals, MonitorData},
     view::{
         util::{fmt_bytes, fmt_int_bytes},
         widgets::tablegrp::TableGroup,
@@ -15,16 +15,29 @@ use crate::{
 
 pub fn render_network(state: &dyn MonitorData, tg: &mut TableGroup) -> Result<()> {
     if let Some(nets) = state.networks().acceptable_to_opt()? {
+        let opts = state.options();
         let nets = nets
             .into_iter()
             .filter(|n| n.name != "lo0")
             .sorted_by(|n1, n2| n1.name.cmp(&n2.name))
             .collect_vec();
-        let tbl = tg.add_table("NETWORK", ["RB/s", "WB/s"]);
+        let tbl = tg.add_table(
+            table_label("NETWORK", opts.io_counters),
+            column_labels(opts.io_counters),
+        );
+        let mut total = (0, 0);
         for n in nets {
+            let (rx, tx) = io_values(n.rx_bytes, n.tx_bytes, &n.totals, opts.io_counters);
+            if !is_loopback(&n.name) {
+                total.0 += rx;
+                total.1 += tx;
+            }
+            tbl.add_row(n.name, format_io(rx, tx, opts.io_counters))
+        }
+        if opts.io_totals {
             tbl.add_row(
-                n.name,
-                [fmt_int_bytes(n.rx_bytes), fmt_int_bytes(n.tx_bytes)],
+                Span::from("total").bold(),
+                format_io(total.0, total.1, opts.io_counters),
             )
         }
     }
@@ -33,21 +46,68 @@ pub fn render_network(state: &dyn MonitorData, tg: &mut TableGroup) -> Result<()
 
 pub fn render_disks(state: &dyn MonitorData, tg: &mut TableGroup) -> Result<()> {
     if let Some(disks) = state.disk_io().acceptable_to_opt()? {
+        let opts = state.options();
         let disks = disks
             .into_iter()
             .sorted_by(|n1, n2| n1.name.cmp(&n2.name))
             .collect_vec();
-        let tbl = tg.add_table("DISK", ["RB/s", "WB/s"]);
+        let tbl = tg.add_table(
+            table_label("DISK", opts.io_counters),
+            column_labels(opts.io_counters),
+        );
+        let mut total = (0, 0);
         for d in disks {
+            let (rx, tx) = io_values(d.rx_bytes, d.tx_bytes, &d.totals, opts.io_counters);
+            total.0 += rx;
+            total.1 += tx;
+            tbl.add_row(d.name, format_io(rx, tx, opts.io_counters))
+        }
+        if opts.io_totals {
             tbl.add_row(
-                d.name,
-                [fmt_int_bytes(d.rx_bytes), fmt_int_bytes(d.tx_bytes)],
+                Span::from("total").bold(),
+                format_io(total.0, total.1, opts.io_counters),
             )
         }
     }
     Ok(())
 }
 
+fn table_label(name: &'static str, mode: IOCounterMode) -> String {
+    match mode {
+        IOCounterMode::Rate => name.to_string(),
+        IOCounterMode::SinceStart => format!("{} since start", name),
+        IOCounterMode::SinceBoot => format!("{} since boot", name),
+    }
+}
+
+fn column_labels(mode: IOCounterMode) -> [&'static str; 2] {
+    match mode {
+        IOCounterMode::Rate => ["RB/s", "WB/s"],
+        _ => ["RB", "WB"],
+    }
+}
+
+/// Select the received and sent values to display for a device.
+fn io_values(rx: u64, tx: u64, totals: &IOTotals, mode: IOCounterMode) -> (u64, u64) {
+    match mode {
+        IOCounterMode::Rate => (rx, tx),
+        IOCounterMode::SinceStart => (totals.rx_start, totals.tx_start),
+        IOCounterMode::SinceBoot => (totals.rx_boot, totals.tx_boot),
+    }
+}
+
+fn format_io(rx: u64, tx: u64, mode: IOCounterMode) -> [String; 2] {
+    match mode {
+        IOCounterMode::Rate => [fmt_int_bytes(rx), fmt_int_bytes(tx)],
+        _ => [fmt_bytes(rx), fmt_bytes(tx)],
+    }
+}
+
+/// Loopback traffic is excluded from network totals, since it never leaves the host.
+fn is_loopback(name: &str) -> bool {
+    name == "lo" || name == "lo0"
+}
+
 pub fn render_filesystems(state: &dyn MonitorData, tg: &mut TableGroup) -> Result<()> {
     if let Some(disks) = state.filesystems().acceptable_to_opt()? {
         let disks = disks
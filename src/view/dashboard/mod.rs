@@ -3,21 +3,26 @@
 use std::cmp::min;
 
 use anyhow::Result;
-use itertools::Itertools;
 use log::*;
 use ratatui::prelude::*;
 
 mod banner;
+mod command;
 mod iotables;
 mod process_table;
 mod quicklook;
 mod summaries;
 
-use banner::render_banner;
+use banner::{render_banner, render_notices};
 use quicklook::render_quicklook;
-use summaries::*;
 
-use crate::{backend::error::BackendErrorFilter, model::MonitorState};
+pub use process_table::{optional_process_columns, sort_columns};
+pub use summaries::{render_summary_detail, Summary};
+
+use crate::{
+    backend::error::BackendErrorFilter,
+    model::{MonitorState, ProcSortOrder},
+};
 
 use self::{
     iotables::{render_disks, render_filesystems, render_network},
@@ -38,7 +43,57 @@ enum HeaderBlock {
     },
 }
 
-pub fn render_dashboard<'b>(frame: &mut Frame, state: &MonitorState<'b>) -> Result<()> {
+/// Screen areas of the dashboard's parts, for handling mouse events.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardLayout {
+    /// The displayed summary boxes.
+    pub summaries: Vec<(Rect, Summary)>,
+    /// The network, disk & filesystem tables.
+    pub tables: Option<Rect>,
+    /// The process table column headers, with their sort orders.
+    pub proc_headers: Vec<(Rect, Option<ProcSortOrder>)>,
+    /// The process table rows.
+    pub proc_rows: Rect,
+}
+
+impl DashboardLayout {
+    /// Get the summary box at a position.
+    pub fn summary_at(&self, pos: Position) -> Option<Summary> {
+        self.summaries
+            .iter()
+            .find(|(r, _)| r.contains(pos))
+            .map(|(_, s)| *s)
+    }
+
+    /// Get the process column header at a position, and its sort order.
+    pub fn proc_header_at(&self, pos: Position) -> Option<Option<ProcSortOrder>> {
+        self.proc_headers
+            .iter()
+            .find(|(r, _)| r.contains(pos))
+            .map(|(_, o)| *o)
+    }
+
+    /// Get the on-screen index of the process table row at a position.
+    pub fn proc_row_at(&self, pos: Position) -> Option<usize> {
+        if self.proc_rows.contains(pos) {
+            Some((pos.y - self.proc_rows.y) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Query whether a position is in the network, disk & filesystem tables.
+    pub fn in_tables(&self, pos: Position) -> bool {
+        self.tables.is_some_and(|r| r.contains(pos))
+    }
+}
+
+/// Render the dashboard, returning the layout of its parts.
+pub fn render_dashboard<'b>(
+    frame: &mut Frame,
+    state: &MonitorState<'b>,
+) -> Result<DashboardLayout> {
+    let mut dl = DashboardLayout::default();
     let layout = Layout::new(
         Direction::Vertical,
         [
@@ -51,32 +106,31 @@ pub fn render_dashboard<'b>(frame: &mut Frame, state: &MonitorState<'b>) -> Resu
     )
     .split(frame.area());
     render_banner(frame, state, layout[0])?;
+    render_notices(frame, state, layout[1])?;
 
-    let summaries = [
-        (cpu_summary(state).acceptable_to_opt()?, 1),
-        (memory_summary(state).acceptable_to_opt()?, 2),
-        (swap_summary(state).acceptable_to_opt()?, 4),
-        (gpu_summary(state).acceptable_to_opt()?, 6),
-        (pressure_summary(state).acceptable_to_opt()?, 5),
-        (load_summary(state).acceptable_to_opt()?, 3),
-    ];
-    let summaries = summaries
-        .into_iter()
-        .flat_map(|(ico, p)| ico.map(|ic| (ic, p)))
-        .collect_vec();
+    let mut summaries = Vec::with_capacity(Summary::ALL.len());
+    for s in Summary::ALL {
+        if let Some(ic) = s.info(state).acceptable_to_opt()? {
+            summaries.push((ic, s));
+        }
+    }
     let mut boxes = Vec::with_capacity(summaries.len() + 1);
     boxes.push(HeaderBlock::Meters(QL_MIN));
     boxes.push(HeaderBlock::Gutter(1));
-    boxes.extend(summaries.iter().map(|(ic, priority)| HeaderBlock::Summary {
+    boxes.extend(summaries.iter().map(|(ic, s)| HeaderBlock::Summary {
         cols: ic.num_cols(),
         col_size: ic.col_width(),
-        priority: *priority,
+        priority: s.priority(),
     }));
     let summary_split = layout_summaries(&boxes, layout[2]);
 
     render_quicklook(frame, state, summary_split[0])?;
-    for (i, (ic, _)) in summaries.into_iter().enumerate() {
-        frame.render_widget(ic, summary_split[i + 2]);
+    for (i, (ic, s)) in summaries.into_iter().enumerate() {
+        let area = summary_split[i + 2];
+        frame.render_widget(ic, area);
+        if area.width > 0 {
+            dl.summaries.push((area, s));
+        }
     }
 
     let mut lsg = TableGroup::new();
@@ -94,14 +148,20 @@ pub fn render_dashboard<'b>(frame: &mut Frame, state: &MonitorState<'b>) -> Resu
             ],
         )
         .split(layout[4]);
-        frame.render_widget(lsg, tables[0]);
+        let area = tables[0];
+        let offset = state
+            .table_scroll
+            .sync(lsg.height() as usize, area.height as usize);
+        lsg.scroll(offset as u16);
+        frame.render_widget(lsg, area);
+        dl.tables = Some(area);
         tables[2]
     } else {
         layout[4]
     };
-    render_process_table(frame, state, pt_area)?;
+    render_process_table(frame, state, pt_area, &mut dl)?;
 
-    Ok(())
+    Ok(dl)
 }
 
 fn layout_summaries(blocks: &[HeaderBlock], area: Rect) -> Vec<Rect> {
@@ -1,19 +1,30 @@
+use std::time::SystemTime;
+
 use anyhow::Result;
+use itertools::Itertools;
 use layout::Flex;
 use log::*;
 use ratatui::{
     prelude::*,
-    widgets::{Cell, Paragraph, Row, Table},
+    widgets::{Cell, Paragraph, Row, Table, TableState},
 };
 
+use super::command::compact_command;
 use crate::{
     backend::error::BackendErrorFilter,
-    model::{process::ProcessList, *},
-    view::util::{fmt_bytes, fmt_duration, fmt_int_bytes},
+    model::{
+        process::{format_cpu_list, ProcessList},
+        tree::ProcessTreeNode,
+        *,
+    },
+    view::dashboard::DashboardLayout,
+    view::util::{fmt_age, fmt_bytes, fmt_duration, fmt_int_bytes, fmt_int_si, fmt_limit},
 };
 
 type ColProc = fn(&dyn MonitorData, &Process) -> Result<String>;
+type ColLine = fn(&dyn MonitorData, &Process) -> Result<Line<'static>>;
 type ColPredicate = fn(&dyn MonitorData) -> bool;
+type ColHeader = fn(&dyn MonitorData) -> String;
 
 struct PTColumn {
     label: &'static str,
@@ -21,7 +32,13 @@ struct PTColumn {
     align: Alignment,
     sort_key: Option<ProcSortOrder>,
     ex_func: ColProc,
+    /// Function extracting styled text, for columns that highlight parts of their values.
+    line_func: Option<ColLine>,
     active_pred: ColPredicate,
+    /// Function computing the header, for columns whose label is not fixed.
+    header_func: Option<ColHeader>,
+    /// Description for optional columns, which are hidden unless selected.
+    optional: Option<&'static str>,
 }
 
 impl PTColumn {
@@ -32,7 +49,10 @@ impl PTColumn {
             align: Alignment::Left,
             sort_key: None,
             ex_func: |_, _| Ok(String::new()),
+            line_func: None,
             active_pred: |_| true,
+            header_func: None,
+            optional: None,
         }
     }
 
@@ -65,6 +85,13 @@ impl PTColumn {
         PTColumn { ex_func, ..self }
     }
 
+    const fn extract_line(self, line_func: ColLine) -> Self {
+        PTColumn {
+            line_func: Some(line_func),
+            ..self
+        }
+    }
+
     const fn condition(self, active_pred: ColPredicate) -> Self {
         PTColumn {
             active_pred,
@@ -72,8 +99,23 @@ impl PTColumn {
         }
     }
 
+    const fn header(self, header_func: ColHeader) -> Self {
+        PTColumn {
+            header_func: Some(header_func),
+            ..self
+        }
+    }
+
+    const fn optional(self, desc: &'static str) -> Self {
+        PTColumn {
+            optional: Some(desc),
+            ..self
+        }
+    }
+
     fn enabled(&self, state: &dyn MonitorData) -> bool {
         (self.active_pred)(state)
+            && (self.optional.is_none() || state.options().proc_columns.contains(self.label))
     }
 }
 
@@ -82,25 +124,111 @@ static COLUMNS: &[PTColumn] = &[
         .min_width(5)
         .align(Alignment::Right)
         .sort(ProcSortOrder::CPU)
-        .extract(|_, proc| Ok(format!("{:.1}", proc.cpu_util * 100.0))),
+        .header(|state| match state.options().cpu_mode {
+            CpuPctMode::PerCore => "CORE%".into(),
+            CpuPctMode::OfTotal => "CPU%".into(),
+        })
+        .extract(|state, proc| {
+            let cpu = subtree(state, proc).map_or(proc.cpu_util, |t| t.cpu_util);
+            Ok(format!("{:.1}", cpu * 100.0))
+        }),
+    PTColumn::new("WAIT%")
+        .width(5)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::SchedWait)
+        .optional("time waiting for a CPU")
+        .extract(|state, proc| {
+            let wait = match proc.sched_wait {
+                Some(w) => Some(w),
+                None => state.process_sched_wait(proc.pid).acceptable_to_opt()?,
+            };
+            Ok(wait
+                .map(|w| format!("{:.1}", w * 100.0))
+                .unwrap_or_default())
+        }),
     PTColumn::new("MEM%")
         .width(5)
         .align(Alignment::Right)
         .sort(ProcSortOrder::Memory)
-        .extract(|_, proc| Ok(format!("{:.1}", proc.mem_util * 100.0))),
+        .extract(|state, proc| {
+            let mem = subtree(state, proc).map_or(proc.mem_util, |t| t.mem_util);
+            Ok(format!("{:.1}", mem * 100.0))
+        }),
     PTColumn::new("VIRT")
+        .sort(ProcSortOrder::Virt)
         .width(5)
         .align(Alignment::Right)
         .extract(|_, proc| Ok(fmt_bytes(proc.mem_virt))),
     PTColumn::new("RES")
+        .sort(ProcSortOrder::Res)
         .width(6)
         .align(Alignment::Right)
         .extract(|_, proc| Ok(fmt_bytes(proc.mem_rss))),
+    PTColumn::new("PSS")
+        .width(6)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::PSS)
+        .optional("proportional set size")
+        .extract(|state, proc| {
+            let mem = mem_detail(state, proc)?;
+            Ok(mem.and_then(|m| m.pss).map(fmt_bytes).unwrap_or_default())
+        }),
+    PTColumn::new("USS")
+        .width(6)
+        .align(Alignment::Right)
+        .optional("unique (private) memory")
+        .extract(|state, proc| {
+            let mem = mem_detail(state, proc)?;
+            Ok(mem.and_then(|m| m.uss).map(fmt_bytes).unwrap_or_default())
+        }),
+    PTColumn::new("SHR")
+        .width(6)
+        .align(Alignment::Right)
+        .optional("shared memory")
+        .extract(|state, proc| {
+            let mem = mem_detail(state, proc)?;
+            Ok(mem
+                .and_then(|m| m.shared)
+                .map(fmt_bytes)
+                .unwrap_or_default())
+        }),
+    PTColumn::new("SWAP")
+        .width(6)
+        .align(Alignment::Right)
+        .optional("swapped memory")
+        .extract(|state, proc| {
+            let mem = mem_detail(state, proc)?;
+            Ok(mem.and_then(|m| m.swap).map(fmt_bytes).unwrap_or_default())
+        }),
+    PTColumn::new("OOM")
+        .width(4)
+        .align(Alignment::Right)
+        .optional("OOM killer score")
+        .extract(|state, proc| {
+            let oom = state.process_oom(proc.pid).acceptable_to_opt()?;
+            Ok(oom.map(|o| o.score.to_string()).unwrap_or_default())
+        }),
+    PTColumn::new("OADJ")
+        .width(5)
+        .align(Alignment::Right)
+        .optional("OOM killer score adjustment")
+        .extract(|state, proc| {
+            let oom = state.process_oom(proc.pid).acceptable_to_opt()?;
+            Ok(oom.map(|o| o.adj.to_string()).unwrap_or_default())
+        }),
     PTColumn::new("PID")
+        .sort(ProcSortOrder::Pid)
         .width(7)
         .align(Alignment::Right)
         .extract(|_, proc| Ok(format!("{}", proc.pid))),
+    PTColumn::new("TGID")
+        .sort(ProcSortOrder::Tgid)
+        .width(7)
+        .align(Alignment::Right)
+        .condition(|state| state.options().threads != ThreadMode::Processes)
+        .extract(|_, proc| Ok(proc.tgid.map(|t| t.to_string()).unwrap_or_default())),
     PTColumn::new("USER")
+        .sort(ProcSortOrder::User)
         .width(8)
         .align(Alignment::Right)
         .extract(|state, proc| {
@@ -117,10 +245,90 @@ static COLUMNS: &[PTColumn] = &[
         .sort(ProcSortOrder::Time)
         .condition(|state| state.backend().has_process_time())
         .extract(|_, proc| Ok(proc.cpu_time.map(fmt_duration).unwrap_or_default())),
+    PTColumn::new("AGE")
+        .width(7)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::Age)
+        .optional("time since the process started")
+        .extract(|_, proc| {
+            Ok(proc
+                .start_time
+                .and_then(|t| SystemTime::now().duration_since(t).ok())
+                .map(fmt_age)
+                .unwrap_or_default())
+        }),
     PTColumn::new("S")
+        .sort(ProcSortOrder::State)
         .width(1)
         .align(Alignment::Center)
         .extract(|_, proc| Ok(proc.status.to_string())),
+    PTColumn::new("THR")
+        .width(4)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::Threads)
+        .optional("number of threads")
+        .extract(|_, proc| Ok(proc.threads.map(|t| t.to_string()).unwrap_or_default())),
+    PTColumn::new("PRI")
+        .width(3)
+        .align(Alignment::Right)
+        .optional("kernel scheduling priority")
+        .extract(|_, proc| Ok(proc.priority.map(|p| p.to_string()).unwrap_or_default())),
+    PTColumn::new("NI")
+        .width(3)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::Nice)
+        .optional("nice value")
+        .extract(|_, proc| Ok(proc.nice.map(|n| n.to_string()).unwrap_or_default())),
+    PTColumn::new("IO")
+        .width(4)
+        .align(Alignment::Left)
+        .optional("I/O scheduling priority")
+        .extract(|state, proc| {
+            let prio = state.process_io_priority(proc.pid).acceptable_to_opt()?;
+            Ok(prio.map(|p| p.label()).unwrap_or_default())
+        }),
+    PTColumn::new("AFF")
+        .width(7)
+        .align(Alignment::Left)
+        .optional("CPU affinity")
+        .extract(|state, proc| {
+            let cpus = state.process_affinity(proc.pid).acceptable_to_opt()?;
+            Ok(cpus.map(|c| format_cpu_list(&c)).unwrap_or_default())
+        }),
+    PTColumn::new("POL")
+        .width(3)
+        .align(Alignment::Left)
+        .optional("scheduling policy")
+        .extract(|_, proc| Ok(proc.policy.map(|p| p.label()).unwrap_or_default().into())),
+    PTColumn::new("CPU#")
+        .width(4)
+        .align(Alignment::Right)
+        .optional("CPU last run on")
+        .extract(|_, proc| Ok(proc.processor.map(|c| c.to_string()).unwrap_or_default())),
+    PTColumn::new("FDS")
+        .width(5)
+        .align(Alignment::Right)
+        .optional("open file descriptors")
+        .extract(|state, proc| {
+            let fds = state.process_fd_usage(proc.pid).acceptable_to_opt()?;
+            Ok(fds.map(|f| f.open.to_string()).unwrap_or_default())
+        }),
+    PTColumn::new("FDLIM")
+        .width(9)
+        .align(Alignment::Right)
+        .optional("open file limit (soft/hard)")
+        .extract(|state, proc| {
+            let fds = state.process_fd_usage(proc.pid).acceptable_to_opt()?;
+            Ok(fds.map(|f| fmt_limit(&f.nofile)).unwrap_or_default())
+        }),
+    PTColumn::new("NPLIM")
+        .width(9)
+        .align(Alignment::Right)
+        .optional("process limit (soft/hard)")
+        .extract(|state, proc| {
+            let fds = state.process_fd_usage(proc.pid).acceptable_to_opt()?;
+            Ok(fds.map(|f| fmt_limit(&f.nproc)).unwrap_or_default())
+        }),
     PTColumn::new("R/s")
         .width(5)
         .align(Alignment::Right)
@@ -131,22 +339,128 @@ static COLUMNS: &[PTColumn] = &[
         .align(Alignment::Right)
         .sort(ProcSortOrder::IO)
         .extract(|_, proc| Ok(proc.io_write.map(fmt_int_bytes).unwrap_or_default())),
+    PTColumn::new("MnF/s")
+        .width(5)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::Faults)
+        .optional("minor page faults per second")
+        .extract(|_, proc| Ok(proc.minor_faults.map(fmt_int_si).unwrap_or_default())),
+    PTColumn::new("MjF/s")
+        .width(5)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::Faults)
+        .optional("major page faults per second")
+        .extract(|_, proc| Ok(proc.major_faults.map(fmt_int_si).unwrap_or_default())),
+    PTColumn::new("VCS/s")
+        .width(5)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::CtxSwitches)
+        .optional("voluntary context switches per second")
+        .extract(|state, proc| {
+            let cs = ctx_switches(state, proc)?;
+            Ok(cs.map(|c| fmt_int_si(c.voluntary)).unwrap_or_default())
+        }),
+    PTColumn::new("ICS/s")
+        .width(5)
+        .align(Alignment::Right)
+        .sort(ProcSortOrder::CtxSwitches)
+        .optional("involuntary context switches per second")
+        .extract(|state, proc| {
+            let cs = ctx_switches(state, proc)?;
+            Ok(cs.map(|c| fmt_int_si(c.involuntary)).unwrap_or_default())
+        }),
+    PTColumn::new("ENV")
+        .min_width(3)
+        .condition(|state| state.options().env_column.is_some())
+        .header(|state| state.options().env_column.clone().unwrap_or_default())
+        .extract(|state, proc| {
+            let var = match &state.options().env_column {
+                Some(v) => v,
+                None => return Ok(String::new()),
+            };
+            let env = state.process_environ(proc.pid).acceptable_to_opt()?;
+            Ok(env
+                .and_then(|env| env.into_iter().find(|(k, _)| k == var))
+                .map(|(_, v)| v)
+                .unwrap_or_default())
+        }),
     PTColumn::new("Command")
+        .sort(ProcSortOrder::Command)
         .min_width(20)
         .align(Alignment::Left)
-        .extract(|state, proc| {
-            let cmd = state.process_cmd_info(proc.pid);
-            Ok(cmd
-                .ok()
-                .map(|c| c.cmdline.join(" "))
-                .unwrap_or_else(|| format!("[{}]", proc.name)))
+        .extract_line(|state, proc| {
+            let cmdline = if proc.tgid.is_some_and(|t| t != proc.pid) {
+                // secondary threads share the command line, so show their own names
+                None
+            } else {
+                let cmd = state.process_cmd_info(proc.pid);
+                cmd.ok().map(|c| c.cmdline).filter(|c| !c.is_empty())
+            };
+            let mut line = match cmdline {
+                Some(cmdline) if state.options().full_commands => Line::from(cmdline.join(" ")),
+                Some(cmdline) => compact_command(&cmdline),
+                // exited processes no longer have command lines to read
+                None if proc.tgid.is_some_and(|t| t != proc.pid)
+                    || proc.change == ProcessChange::Exited =>
+                {
+                    Line::from(proc.name.clone())
+                }
+                None => Line::from(format!("[{}]", proc.name)),
+            };
+            if let Some(node) = &proc.tree {
+                line.spans.insert(0, Span::raw(node.prefix.clone()));
+            }
+            Ok(line)
         }),
 ];
 
+/// Get the labels and descriptions of the optional process table columns.
+pub fn optional_process_columns() -> Vec<(&'static str, &'static str)> {
+    COLUMNS
+        .iter()
+        .filter_map(|c| c.optional.map(|d| (c.label, d)))
+        .collect()
+}
+
+/// Get the sort orders of the displayed process table columns, in order.
+pub fn sort_columns(state: &dyn MonitorData) -> Vec<ProcSortOrder> {
+    COLUMNS
+        .iter()
+        .filter(|c| c.enabled(state))
+        .filter_map(|c| c.sort_key)
+        .dedup()
+        .collect()
+}
+
+/// Get a process's tree node, if subtree totals are enabled.
+fn subtree<'p>(state: &dyn MonitorData, proc: &'p Process) -> Option<&'p ProcessTreeNode> {
+    proc.tree.as_ref().filter(|_| state.options().tree_totals)
+}
+
+/// Get a process's detailed memory usage, loading it if the list did not.
+fn mem_detail(state: &dyn MonitorData, proc: &Process) -> Result<Option<ProcessMemory>> {
+    if let Some(mem) = &proc.mem_detail {
+        Ok(Some(mem.clone()))
+    } else {
+        Ok(state.process_memory(proc.pid).acceptable_to_opt()?)
+    }
+}
+
+fn ctx_switches(state: &dyn MonitorData, proc: &Process) -> Result<Option<ContextSwitches>> {
+    match (proc.vol_ctx_switches, proc.invol_ctx_switches) {
+        (Some(voluntary), Some(involuntary)) => Ok(Some(ContextSwitches {
+            voluntary,
+            involuntary,
+        })),
+        _ => Ok(state.process_ctx_switches(proc.pid).acceptable_to_opt()?),
+    }
+}
+
 pub fn render_process_table<'b>(
     frame: &mut Frame,
     state: &MonitorState<'b>,
     area: Rect,
+    dl: &mut DashboardLayout,
 ) -> Result<()> {
     let mut procs = if let Some(ps) = state.processes().acceptable_to_opt()? {
         ps
@@ -158,7 +472,7 @@ pub fn render_process_table<'b>(
 
     let layout = Layout::new(
         Direction::Vertical,
-        &[
+        [
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Min(5),
@@ -166,8 +480,11 @@ pub fn render_process_table<'b>(
     )
     .split(area);
 
+    let page = layout[2].height.saturating_sub(1) as usize;
+    let selected = state.selection.sync(&procs, page, procs.pinned().min(page));
+
     render_headline(state, &procs, frame, layout[0])?;
-    render_table(state, &procs, frame, layout[2])?;
+    render_table(state, &procs, selected, frame, layout[2], dl)?;
 
     Ok(())
 }
@@ -180,11 +497,31 @@ fn render_headline<'b>(
 ) -> Result<()> {
     let counts = procs.counts();
     let hl = Line::from(vec![
-        Span::from("TASKS").bold(),
+        Span::from(match state.options.threads {
+            ThreadMode::Processes => "TASKS".into(),
+            ThreadMode::AllThreads => "THREADS".into(),
+            ThreadMode::ProcessThreads(pid) => format!("THREADS OF {}", pid),
+        })
+        .bold(),
         Span::from(format!(
             " {} run, {} slp, {} oth",
             counts.running, counts.sleeping, counts.other
         )),
+        Span::from(match procs.pinned() {
+            0 => String::new(),
+            n => format!(", {} pinned", n),
+        }),
+        Span::from(match procs.hidden() {
+            0 => String::new(),
+            n => format!(", {} hidden", n),
+        }),
+        Span::from(match state.process_churn().acceptable_to_opt()? {
+            Some(c) if c.started > 0.0 || c.exited > 0.0 => format!(
+                ", {:.1} started / {:.1} exited per second",
+                c.started, c.exited
+            ),
+            _ => String::new(),
+        }),
         Span::from(" sorted"),
         Span::from(if state.proc_sort.is_none() {
             " automatically"
@@ -197,7 +534,46 @@ fn render_headline<'b>(
             ProcSortOrder::Memory => "memory",
             ProcSortOrder::IO => "total I/O",
             ProcSortOrder::Time => "time",
+            ProcSortOrder::PSS => "PSS",
+            ProcSortOrder::Faults => "page faults",
+            ProcSortOrder::CtxSwitches => "context switches",
+            ProcSortOrder::SchedWait => "run queue wait",
+            ProcSortOrder::Threads => "thread count",
+            ProcSortOrder::Nice => "nice",
+            ProcSortOrder::Age => "age",
+            ProcSortOrder::Pid => "PID",
+            ProcSortOrder::Tgid => "TGID",
+            ProcSortOrder::User => "user",
+            ProcSortOrder::Name => "name",
+            ProcSortOrder::Command => "command",
+            ProcSortOrder::State => "state",
+            ProcSortOrder::Virt => "virtual memory",
+            ProcSortOrder::Res => "resident memory",
+        }),
+        Span::from(if procs.is_reversed() {
+            " (reversed)"
+        } else {
+            ""
+        }),
+        Span::from(match (procs.is_tree(), state.options.tree_totals) {
+            (false, _) => "",
+            (true, false) => " in tree",
+            (true, true) => " in tree with subtree totals",
+        }),
+        Span::from(match &state.options.proc_filter {
+            Some(f) => format!(" matching \"{}\"", f.pattern()),
+            None => String::new(),
+        }),
+        Span::from(match &state.options.proc_user {
+            Some(u) => format!(" for user {}", u),
+            None => String::new(),
         }),
+        match state.selection.pid {
+            Some(pid) if state.selection.exited() => {
+                Span::from(format!("  (PID {} exited)", pid)).fg(Color::Red)
+            }
+            _ => Span::from(""),
+        },
     ]);
     let hl = Paragraph::new(vec![hl]);
 
@@ -208,8 +584,10 @@ fn render_headline<'b>(
 fn render_table<'b>(
     state: &MonitorState<'b>,
     procs: &ProcessList,
+    selected: Option<usize>,
     frame: &mut Frame,
     area: Rect,
+    dl: &mut DashboardLayout,
 ) -> Result<()> {
     debug!("proctbl: rendering {} processes in {:?}", procs.len(), area);
     let mut widths: Vec<_> = COLUMNS
@@ -218,16 +596,34 @@ fn render_table<'b>(
         .map(|c| c.constraint)
         .collect();
 
-    let mut rows = Vec::with_capacity(procs.len());
-    for proc in procs.iter() {
-        rows.push(process_row(state, proc, &mut widths)?);
+    // only extract visible rows, since some columns are expensive to load
+    let visible = area.height.saturating_sub(1) as usize;
+    let offset = state.selection.offset();
+    // pinned processes stay at the top, with the rest scrolling beneath them
+    let pinned = procs.pinned().min(visible);
+    let shown = procs
+        .iter()
+        .take(pinned)
+        .chain(procs.iter().skip(pinned + offset).take(visible - pinned));
+    let mut rows = Vec::with_capacity(visible);
+    for (i, proc) in shown.enumerate() {
+        let row = process_row(state, proc, &mut widths)?;
+        rows.push(if i + 1 == pinned {
+            row.underlined()
+        } else {
+            row
+        });
     }
+    let n_rows = rows.len();
 
     let header: Vec<_> = COLUMNS
         .iter()
         .filter(|c| c.enabled(state))
         .map(|c| {
-            let span = Span::from(c.label);
+            let span = match c.header_func {
+                Some(f) => Span::from(f(state)),
+                None => Span::from(c.label),
+            };
             let span = match c.sort_key {
                 Some(s) if s == procs.active_sort_order() => span.bold().underlined(),
                 _ => span,
@@ -235,12 +631,36 @@ fn render_table<'b>(
             Cell::from(Line::from(span).alignment(c.align))
         })
         .collect();
+
+    // lay out the columns the way the table does, to locate the headers
+    let sel_width = if selected.is_some() { 1 } else { 0 };
+    let [_, cols_area] =
+        Layout::horizontal([Constraint::Length(sel_width), Constraint::Fill(0)]).areas(area);
+    let col_areas = Layout::horizontal(&widths)
+        .flex(Flex::Legacy)
+        .spacing(1)
+        .split(cols_area);
+    dl.proc_headers = COLUMNS
+        .iter()
+        .filter(|c| c.enabled(state))
+        .zip(col_areas.iter())
+        .map(|(c, r)| (Rect { height: 1, ..*r }, c.sort_key))
+        .collect();
+    dl.proc_rows = Rect {
+        y: area.y + 1,
+        height: n_rows as u16,
+        ..area
+    };
+
     let table = Table::new(rows, &widths)
         .header(Row::new(header))
         .column_spacing(1)
         .flex(Flex::Legacy)
+        .row_highlight_style(Style::new().reversed())
         .highlight_symbol(">");
-    frame.render_widget(table, area);
+    let selected = selected.map(|i| if i < pinned { i } else { i - offset });
+    let mut table_state = TableState::default().with_selected(selected);
+    frame.render_stateful_widget(table, area, &mut table_state);
     Ok(())
 }
 
@@ -255,15 +675,42 @@ fn process_row<'a, 'b>(
             continue;
         }
 
-        let text = (col.ex_func)(state, proc)?;
-        let len = text.len();
+        let line = match col.line_func {
+            Some(f) => f(state, proc)?,
+            None => Line::from((col.ex_func)(state, proc)?),
+        };
+        let len = line.width();
         if let Constraint::Min(w) = widths[i] {
             if len <= 20 && len > (w as usize) {
                 widths[i] = Constraint::Min(len as u16)
             }
         }
-        let line = Line::from(text).alignment(col.align);
+        let line = line.alignment(col.align);
         cells.push(line);
     }
-    Ok(Row::new(cells))
+    // later styles take precedence: tags over warnings over new and exited rows
+    let mut style = match proc.change {
+        ProcessChange::Started => Style::new().fg(Color::Green),
+        ProcessChange::Exited => Style::new().fg(Color::DarkGray),
+        ProcessChange::Unchanged => Style::new(),
+    };
+    if fd_warning(state, proc)? {
+        style = style.patch(Style::new().fg(Color::Red));
+    }
+    if state.is_tagged(proc) {
+        style = style.patch(Style::new().fg(Color::Yellow).bold());
+    }
+    Ok(Row::new(cells).style(style))
+}
+
+/// Check whether a process is close to its open file limit.
+fn fd_warning(state: &MonitorState<'_>, proc: &Process) -> Result<bool> {
+    let warn = state.options.fd_warn_frac;
+    if warn <= 0.0 {
+        return Ok(false);
+    }
+    let fds = state.process_fd_usage(proc.pid).acceptable_to_opt()?;
+    Ok(fds
+        .and_then(|f| f.nofile_frac())
+        .is_some_and(|frac| frac >= warn))
 }
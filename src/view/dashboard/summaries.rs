@@ -1,16 +1,25 @@
 //! Summary box displays.
 
+use std::time::Duration;
+
+use anyhow::Result;
+use ratatui::layout::{Alignment, Constraint, Layout};
 use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Clear, Padding, Paragraph, Row, Table};
+use ratatui::Frame;
 
+use crate::backend::error::BackendErrorFilter;
 use crate::backend::BackendError;
 use crate::backend::BackendResult;
 use crate::model::cpu::ExtendedCPU;
 use crate::model::ExtendedMemory;
 use crate::model::MonitorData;
-use crate::view::util::fmt_si_val;
+use crate::view::popup::popup_area;
+use crate::view::util::{fmt_bytes, fmt_duration, fmt_si_val};
 use crate::view::widgets::infocols::{ICEntry, InfoCols};
 
-pub fn cpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn cpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let cpu = state.global_cpu()?;
     let mut display = InfoCols::new().add(
         ICEntry::new("CPU")
@@ -42,7 +51,7 @@ pub fn cpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     Ok(display)
 }
 
-pub fn memory_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn memory_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let mem = state.memory()?;
     let ic = InfoCols::new()
         .add(
@@ -76,7 +85,7 @@ pub fn memory_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     Ok(ic)
 }
 
-pub fn swap_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn swap_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let swp = state.swap()?;
     let ic = InfoCols::new()
         .add(
@@ -90,7 +99,7 @@ pub fn swap_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     Ok(ic)
 }
 
-pub fn gpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn gpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let gpus = state.gpus()?;
     if gpus.is_empty() {
         Err(BackendError::NotAvailable)
@@ -111,8 +120,8 @@ pub fn gpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
         let tot_mem_avail: u64 = gpus.iter().map(|g| g.mem_avail).sum();
         let mut ic = InfoCols::new()
             .add_count("GPUs", n as u64)
-            .add_pct("gpu", tot_gpu as f32 / n as f32 / 100.0)
-            .add_pct("gpu", tot_mem_util as f32 / n as f32 / 100.0)
+            .add_pct("gpu", tot_gpu / n as f32 / 100.0)
+            .add_pct("gpu", tot_mem_util / n as f32 / 100.0)
             .add_bytes("avail", tot_mem_avail);
         for gpu in gpus.iter() {
             ic = ic
@@ -127,7 +136,7 @@ pub fn gpu_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     }
 }
 
-pub fn pressure_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn pressure_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let press = state.pressure()?;
     Ok(InfoCols::new()
         .add(ICEntry::new("PSI").string("10s"))
@@ -136,7 +145,7 @@ pub fn pressure_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
         .add_pct("io", press.io_psi.avg10))
 }
 
-pub fn load_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
+fn load_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
     let ncpus = state.cpu_count()? as f32;
     let load = state.load_avg()?;
     Ok(InfoCols::new()
@@ -175,3 +184,310 @@ pub fn load_summary(state: &dyn MonitorData) -> BackendResult<InfoCols> {
                 })),
         ))
 }
+
+/// The summary boxes shown at the top of the dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Summary {
+    CPU,
+    Memory,
+    Swap,
+    GPU,
+    Pressure,
+    Load,
+}
+
+impl Summary {
+    /// All summaries, in display order.
+    pub const ALL: [Summary; 6] = [
+        Summary::CPU,
+        Summary::Memory,
+        Summary::Swap,
+        Summary::GPU,
+        Summary::Pressure,
+        Summary::Load,
+    ];
+
+    /// Get the title for the summary's detail view.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Summary::CPU => "CPU",
+            Summary::Memory => "Memory",
+            Summary::Swap => "Swap",
+            Summary::GPU => "GPU",
+            Summary::Pressure => "Pressure stall information",
+            Summary::Load => "Load average",
+        }
+    }
+
+    /// Get the priority for allocating header space (lower is first).
+    pub fn priority(&self) -> u16 {
+        match self {
+            Summary::CPU => 1,
+            Summary::Memory => 2,
+            Summary::Load => 3,
+            Summary::Swap => 4,
+            Summary::Pressure => 5,
+            Summary::GPU => 6,
+        }
+    }
+
+    /// Get the information columns for this summary.
+    pub fn info(&self, state: &dyn MonitorData) -> BackendResult<InfoCols> {
+        match self {
+            Summary::CPU => cpu_summary(state),
+            Summary::Memory => memory_summary(state),
+            Summary::Swap => swap_summary(state),
+            Summary::GPU => gpu_summary(state),
+            Summary::Pressure => pressure_summary(state),
+            Summary::Load => load_summary(state),
+        }
+    }
+}
+
+/// A table of details beyond what fits in a summary box.
+struct DetailTable {
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl DetailTable {
+    fn new(header: Vec<&'static str>) -> DetailTable {
+        DetailTable {
+            header,
+            rows: Vec::new(),
+        }
+    }
+
+    fn row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /// Get the width of each column.
+    fn widths(&self) -> Vec<u16> {
+        let mut widths: Vec<u16> = self.header.iter().map(|h| h.len() as u16).collect();
+        for row in &self.rows {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.chars().count() as u16);
+            }
+        }
+        widths
+    }
+
+    /// Get the width of the table, including column spacing.
+    fn width(&self) -> u16 {
+        let widths = self.widths();
+        widths.iter().sum::<u16>() + widths.len().saturating_sub(1) as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.rows.len() as u16 + 1
+    }
+
+    fn table(&self) -> Table<'_> {
+        // the first column is a label, the rest are right-aligned values
+        let widths = self.widths();
+        let cells = |row: Vec<String>| -> Row<'_> {
+            Row::new(row.into_iter().enumerate().map(|(i, cell)| {
+                let line = Line::from(cell);
+                if i == 0 {
+                    line
+                } else {
+                    line.right_aligned()
+                }
+            }))
+        };
+        let header = cells(self.header.iter().map(|h| h.to_string()).collect()).bold();
+        let rows = self.rows.iter().map(|r| cells(r.clone()));
+        Table::new(rows, widths.into_iter().map(Constraint::Length)).header(header)
+    }
+}
+
+fn fmt_pct(frac: f32) -> String {
+    format!("{:.1}%", frac * 100.0)
+}
+
+fn cpu_detail(state: &dyn MonitorData) -> BackendResult<DetailTable> {
+    let mut table = DetailTable::new(vec![
+        "CPU", "USED", "USER", "SYSTEM", "NICE", "IOWAIT", "IRQ", "STEAL",
+    ]);
+    for (i, cpu) in state.cpus()?.into_iter().enumerate() {
+        let mut row = vec![format!("cpu{}", i), fmt_pct(cpu.utilization)];
+        if let ExtendedCPU::Linux(lcpu) = cpu.extended {
+            row.extend(
+                [
+                    lcpu.user,
+                    lcpu.system,
+                    lcpu.nice,
+                    lcpu.iowait,
+                    lcpu.irq,
+                    lcpu.steal,
+                ]
+                .map(fmt_pct),
+            );
+        }
+        table.row(row);
+    }
+    Ok(table)
+}
+
+fn memory_detail(state: &dyn MonitorData) -> BackendResult<DetailTable> {
+    let mem = state.memory()?;
+    let mut table = DetailTable::new(vec!["MEMORY", "SIZE", "OF TOTAL"]);
+    let mut add = |label: &str, bytes: Option<u64>| {
+        if let Some(bytes) = bytes {
+            table.row(vec![
+                label.to_string(),
+                fmt_bytes(bytes),
+                fmt_pct(bytes as f32 / mem.total as f32),
+            ]);
+        }
+    };
+    add("total", Some(mem.total));
+    add("used", Some(mem.used));
+    add("free", Some(mem.free));
+    add("available", Some(mem.free + mem.freeable));
+    if let ExtendedMemory::Linux(linux) = &mem.extended {
+        add("active", Some(linux.active));
+        add("inactive", Some(linux.inactive));
+        add("anonymous", linux.anon);
+        add("mapped", Some(linux.mapped));
+        add("shared", linux.shared);
+        add("buffers", Some(linux.buffers));
+        add("cached", Some(linux.cached));
+        add("swap cached", Some(linux.swap_cached));
+        add("dirty", Some(linux.dirty));
+        add("writeback", Some(linux.writeback));
+        add("unevictable", linux.unevictable);
+        add("slab", Some(linux.slab));
+        add("reclaimable", linux.reclaimable);
+        add("kernel stack", linux.kernel_stack);
+        add("page tables", linux.page_tables);
+        add("ZFS ARC", linux.arc);
+        add("committed", Some(linux.committed));
+        add("commit limit", linux.commit_limit);
+    }
+    Ok(table)
+}
+
+fn gpu_detail(state: &dyn MonitorData) -> BackendResult<DetailTable> {
+    let mut table = DetailTable::new(vec!["GPU", "UTIL", "MEM", "USED", "TOTAL", "TEMP", "POWER"]);
+    for gpu in state.gpus()? {
+        table.row(vec![
+            gpu.name,
+            fmt_pct(gpu.gpu_util),
+            fmt_pct(gpu.mem_util),
+            fmt_bytes(gpu.mem_used),
+            fmt_bytes(gpu.mem_total),
+            gpu.temp.map(|t| format!("{:.0}°C", t)).unwrap_or_default(),
+            gpu.power
+                .map(|p| format!("{}W", fmt_si_val(p)))
+                .unwrap_or_default(),
+        ]);
+    }
+    Ok(table)
+}
+
+fn pressure_detail(state: &dyn MonitorData) -> BackendResult<DetailTable> {
+    let press = state.pressure()?;
+    let mut table = DetailTable::new(vec!["STALLED", "10s", "60s", "300s", "TOTAL"]);
+    for (label, psi) in [
+        ("some cpu", &press.cpu_psi),
+        ("some mem", &press.mem_psi),
+        ("full mem", &press.mem_full_psi),
+        ("some io", &press.io_psi),
+        ("full io", &press.io_full_psi),
+    ] {
+        table.row(vec![
+            label.to_string(),
+            format!("{:.2}%", psi.avg10),
+            format!("{:.2}%", psi.avg60),
+            format!("{:.2}%", psi.avg300),
+            fmt_duration(Duration::from_micros(psi.total)),
+        ]);
+    }
+    Ok(table)
+}
+
+fn load_detail(state: &dyn MonitorData) -> BackendResult<DetailTable> {
+    let ncpus = state.cpu_count()? as f32;
+    let load = state.load_avg()?;
+    let mut table = DetailTable::new(vec!["LOAD", "AVERAGE", "PER CORE"]);
+    for (label, avg) in [
+        ("1min", load.one),
+        ("5min", load.five),
+        ("15min", load.fifteen),
+    ] {
+        table.row(vec![
+            label.to_string(),
+            format!("{:.2}", avg),
+            format!("{:.2}", avg / ncpus),
+        ]);
+    }
+    Ok(table)
+}
+
+/// Render a summary in full, as a popup over the dashboard.
+///
+/// The summary box is shown at the top, followed by the details the box has no
+/// room for.
+pub fn render_summary_detail(
+    frame: &mut Frame,
+    state: &dyn MonitorData,
+    summary: Summary,
+) -> Result<()> {
+    let block = Block::bordered()
+        .title(summary.title())
+        .title_style(Style::new().fg(Color::Blue).bold())
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+
+    let info = summary.info(state).acceptable_to_opt()?;
+    let detail = match summary {
+        Summary::CPU => cpu_detail(state),
+        Summary::Memory => memory_detail(state),
+        Summary::GPU => gpu_detail(state),
+        Summary::Pressure => pressure_detail(state),
+        Summary::Load => load_detail(state),
+        // the summary box already has everything we know about swap
+        Summary::Swap => Err(BackendError::NotAvailable),
+    }
+    .acceptable_to_opt()?;
+
+    let width = match &info {
+        Some(ic) => ic.num_cols() * ic.col_width(),
+        None => 0,
+    };
+    let width = width
+        .max(detail.as_ref().map(|d| d.width()).unwrap_or_default())
+        .max(summary.title().len() as u16 + 2)
+        .max(24)
+        + 4;
+    let detail_height = detail.as_ref().map(|d| d.height() + 1).unwrap_or_default();
+    let area = popup_area(frame.area(), width, 4 + detail_height + 3);
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [body, detail_area, hint] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+    match info {
+        Some(ic) => frame.render_widget(ic, body),
+        None => frame.render_widget(Paragraph::new("no longer available"), body),
+    }
+    if let Some(detail) = &detail {
+        let [_, table_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(detail_area);
+        frame.render_widget(detail.table(), table_area);
+    }
+    frame.render_widget(
+        Paragraph::new("esc to close").style(Style::new().italic()),
+        hint,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+//! Process detail view.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Padding, Paragraph, Sparkline, Wrap};
+use ratatui::Frame;
+
+use crate::backend::error::BackendErrorFilter;
+use crate::model::{MonitorData, MonitorState, Process, RunningProcesses};
+
+use super::util::{fmt_bytes, fmt_duration, fmt_limit};
+
+/// Maximum number of ancestors to show in the parent chain.
+const MAX_PARENTS: usize = 32;
+
+/// Render the detail view for a process, with its CPU (in tenths of a
+/// percent) and RSS history.
+pub fn render_process_detail(
+    frame: &mut Frame,
+    state: &MonitorState<'_>,
+    pid: u32,
+    cpu_hist: &[u64],
+    rss_hist: &[u64],
+) -> Result<()> {
+    let procs = state.processes()?;
+    let proc = procs.iter().find(|p| p.pid == pid);
+
+    let title = match proc {
+        Some(p) => format!("Process {} ({})", pid, p.name),
+        None => format!("Process {}", pid),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .title_style(Style::new().fg(Color::Blue).bold())
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+    let area = frame.area();
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [info, cpu_label, cpu_area, rss_label, rss_area, hint] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(4),
+        Constraint::Length(1),
+        Constraint::Length(4),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let hint_text = "o open files, M memory maps, e environment, esc to close";
+    frame.render_widget(Paragraph::new(hint_text).style(Style::new().italic()), hint);
+
+    let proc = match proc {
+        Some(p) => p,
+        None => {
+            let msg = Span::styled("process has exited", Style::new().fg(Color::Red));
+            frame.render_widget(Paragraph::new(msg), info);
+            return Ok(());
+        }
+    };
+
+    let lines = detail_lines(state, proc)?;
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), info);
+
+    let cpu_now = cpu_hist.last().copied().unwrap_or_default() as f32 / 10.0;
+    let cpu_max = cpu_hist.iter().max().copied().unwrap_or_default() as f32 / 10.0;
+    frame.render_widget(
+        Line::from(format!("CPU {:.1}% (max {:.1}%)", cpu_now, cpu_max)).bold(),
+        cpu_label,
+    );
+    frame.render_widget(
+        Sparkline::default()
+            .data(tail(cpu_hist, cpu_area.width))
+            .style(Style::new().fg(Color::Green)),
+        cpu_area,
+    );
+
+    let rss_now = rss_hist.last().copied().unwrap_or_default();
+    let rss_max = rss_hist.iter().max().copied().unwrap_or_default();
+    frame.render_widget(
+        Line::from(format!(
+            "RSS {} (max {})",
+            fmt_bytes(rss_now),
+            fmt_bytes(rss_max)
+        ))
+        .bold(),
+        rss_label,
+    );
+    frame.render_widget(
+        Sparkline::default()
+            .data(tail(rss_hist, rss_area.width))
+            .style(Style::new().fg(Color::Magenta)),
+        rss_area,
+    );
+
+    Ok(())
+}
+
+/// Get the most recent samples that fit in a width.
+fn tail(data: &[u64], width: u16) -> &[u64] {
+    &data[data.len().saturating_sub(width as usize)..]
+}
+
+/// Create a labeled detail line.
+fn field<'a, S: Into<String>>(label: &'a str, value: S) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(format!("{:<12}", label), Style::new().bold()),
+        Span::raw(value.into()),
+    ])
+}
+
+fn detail_lines<'a>(state: &MonitorState<'_>, proc: &Process) -> Result<Vec<Line<'a>>> {
+    let unknown = || "??".to_string();
+    let cmd = state.process_cmd_info(proc.pid).acceptable_to_opt()?;
+    let details = state
+        .process_details(proc.pid)
+        .acceptable_to_opt()?
+        .unwrap_or_default();
+    let mem = state
+        .process_memory(proc.pid)
+        .acceptable_to_opt()?
+        .unwrap_or_default();
+    let fds = state.process_fd_usage(proc.pid).acceptable_to_opt()?;
+
+    let mut lines = Vec::new();
+    lines.push(field(
+        "Command",
+        cmd.as_ref()
+            .map(|c| c.cmdline.join(" "))
+            .filter(|c| !c.is_empty())
+            .unwrap_or_else(|| format!("[{}]", proc.name)),
+    ));
+    lines.push(field(
+        "Executable",
+        cmd.map(|c| c.exe).unwrap_or_else(unknown),
+    ));
+    lines.push(field("Directory", details.cwd.unwrap_or_else(unknown)));
+
+    let user = match proc.uid {
+        Some(uid) => {
+            let name = state.lookup_user(uid)?.unwrap_or_else(unknown);
+            format!("{} ({})", name, uid)
+        }
+        None => unknown(),
+    };
+    let group = match details.gid {
+        Some(gid) => {
+            let name = state.lookup_group(gid)?.unwrap_or_else(unknown);
+            format!("{} ({})", name, gid)
+        }
+        None => unknown(),
+    };
+    lines.push(field("User", format!("{}, group {}", user, group)));
+    lines.push(field("Parents", parent_chain(state, proc)?));
+    if let Some(cgroup) = details.cgroup {
+        lines.push(field("Cgroup", cgroup));
+    }
+
+    let mut sched = format!("{}", proc.status);
+    if let Some(threads) = proc.threads {
+        sched.push_str(&format!(", {} threads", threads));
+    }
+    if let Some(nice) = proc.nice {
+        sched.push_str(&format!(", nice {}", nice));
+    }
+    lines.push(field("State", sched));
+    lines.push(field(
+        "CPU time",
+        match (proc.cpu_utime, proc.cpu_stime) {
+            (Some(u), Some(s)) => format!("{} user, {} system", fmt_duration(u), fmt_duration(s)),
+            _ => proc.cpu_time.map(fmt_duration).unwrap_or_else(unknown),
+        },
+    ));
+
+    let opt_bytes = |v: Option<u64>| v.map(fmt_bytes).unwrap_or_else(unknown);
+    lines.push(field(
+        "Memory",
+        format!(
+            "RSS {}, virtual {}, PSS {}, USS {}, shared {}, swap {}",
+            fmt_bytes(proc.mem_rss),
+            fmt_bytes(proc.mem_virt),
+            opt_bytes(mem.pss),
+            opt_bytes(mem.uss),
+            opt_bytes(mem.shared),
+            opt_bytes(mem.swap),
+        ),
+    ));
+    lines.push(field(
+        "I/O",
+        format!(
+            "read {} ({}/s), written {} ({}/s)",
+            opt_bytes(details.io_read_total),
+            opt_bytes(proc.io_read),
+            opt_bytes(details.io_write_total),
+            opt_bytes(proc.io_write),
+        ),
+    ));
+    if let Some(fds) = fds {
+        lines.push(field(
+            "Limits",
+            format!(
+                "{} open files (limit {}), processes {}",
+                fds.open,
+                fmt_limit(&fds.nofile),
+                fmt_limit(&fds.nproc)
+            ),
+        ));
+    }
+    lines.push(field(
+        "Environment",
+        details
+            .env_count
+            .map(|n| format!("{} variables", n))
+            .unwrap_or_else(unknown),
+    ));
+
+    Ok(lines)
+}
+
+/// Describe the chain of parent processes.
+fn parent_chain(state: &MonitorState<'_>, proc: &Process) -> Result<String> {
+    // look up parents in the full process list, since the display may be filtered
+    let all = state.backend.processes().acceptable_to_opt()?;
+    let parents: HashMap<u32, (&str, Option<u32>)> = all
+        .iter()
+        .flatten()
+        .map(|p| (p.pid, (p.name.as_str(), p.ppid)))
+        .collect();
+
+    let mut chain = Vec::new();
+    let mut cur = proc.ppid;
+    while let Some(pid) = cur.filter(|p| *p > 0) {
+        if chain.len() >= MAX_PARENTS {
+            chain.push("…".to_string());
+            break;
+        }
+        match parents.get(&pid) {
+            Some((name, ppid)) => {
+                chain.push(format!("{} ({})", name, pid));
+                cur = *ppid;
+            }
+            None => {
+                chain.push(pid.to_string());
+                break;
+            }
+        }
+    }
+
+    if chain.is_empty() {
+        Ok("none".into())
+    } else {
+        Ok(chain.join(" ← "))
+    }
+}
@@ -0,0 +1,108 @@
+//! Process environment view.
+
+use anyhow::Result;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Padding, Paragraph};
+use ratatui::Frame;
+
+use crate::backend::{BackendError, BackendResult};
+use crate::model::{MonitorState, RunningProcesses, ScrollPosition};
+
+use super::render_prompt;
+
+/// Render a process's environment, showing only variables matching the
+/// search text (if any).
+pub fn render_environment(
+    frame: &mut Frame,
+    state: &MonitorState<'_>,
+    pid: u32,
+    search: &str,
+    editing: bool,
+    scroll: &ScrollPosition,
+) -> Result<()> {
+    let env = state.process_environ(pid);
+    let title = match (&env, search) {
+        (Ok(env), "") => format!("Environment of process {} ({} variables)", pid, env.len()),
+        (Ok(env), s) => format!(
+            "Environment of process {} ({} of {} variables matching \"{}\")",
+            pid,
+            env.iter().filter(|v| matches(v, s)).count(),
+            env.len(),
+            s
+        ),
+        (Err(_), _) => format!("Environment of process {}", pid),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .title_style(Style::new().fg(Color::Blue).bold())
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+    let area = frame.area();
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [list_area, hint] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new("/ to search, ↑↓ PgUp PgDn to scroll, esc to close")
+            .style(Style::new().italic()),
+        hint,
+    );
+
+    match filtered(env, search) {
+        Ok(vars) => {
+            let page = list_area.height as usize;
+            let offset = scroll.sync(vars.len(), page);
+            let lines: Vec<_> = vars
+                .iter()
+                .skip(offset)
+                .take(page)
+                .map(|(k, v)| {
+                    Line::from(vec![
+                        Span::styled(k.as_str(), Style::new().fg(Color::Cyan).bold()),
+                        Span::raw("="),
+                        Span::raw(v.as_str()),
+                    ])
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(lines), list_area);
+        }
+        Err(e) => {
+            let msg = match e {
+                BackendError::NotAllowed => "permission denied".to_string(),
+                BackendError::NotFound => "process has exited".to_string(),
+                e => format!("environment unavailable: {}", e),
+            };
+            let msg = Span::styled(msg, Style::new().fg(Color::Red));
+            frame.render_widget(Paragraph::new(msg), list_area);
+        }
+    }
+
+    if editing {
+        render_prompt(frame, "Search", search)?;
+    }
+
+    Ok(())
+}
+
+/// Check whether a variable's name or value contains the search text
+/// (case-insensitively).
+fn matches((name, value): &(String, String), search: &str) -> bool {
+    let search = search.to_lowercase();
+    name.to_lowercase().contains(&search) || value.to_lowercase().contains(&search)
+}
+
+fn filtered(
+    env: BackendResult<Vec<(String, String)>>,
+    search: &str,
+) -> BackendResult<Vec<(String, String)>> {
+    let mut env = env?;
+    if !search.is_empty() {
+        env.retain(|v| matches(v, search));
+    }
+    Ok(env)
+}
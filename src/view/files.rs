@@ -0,0 +1,111 @@
+//! Open files view.
+
+use anyhow::Result;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Cell, Clear, Padding, Paragraph, Row, Table};
+use ratatui::Frame;
+
+use crate::backend::error::BackendErrorFilter;
+use crate::model::files::{OpenFile, OpenFileKind};
+use crate::model::{MonitorState, RunningProcesses, ScrollPosition};
+
+pub fn render_open_files(
+    frame: &mut Frame,
+    state: &MonitorState<'_>,
+    pid: u32,
+    scroll: &ScrollPosition,
+) -> Result<()> {
+    let files = state.process_open_files(pid).acceptable_to_opt();
+    let title = match &files {
+        Ok(Some(files)) => format!("Open files of process {} ({} open)", pid, files.len()),
+        _ => format!("Open files of process {}", pid),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .title_style(Style::new().fg(Color::Blue).bold())
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+    let area = frame.area();
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [table_area, hint] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new("↑↓ PgUp PgDn to scroll, esc to close").style(Style::new().italic()),
+        hint,
+    );
+
+    let files = match files {
+        Ok(Some(files)) => files,
+        Ok(None) => {
+            let msg = Span::styled(
+                "open files unavailable (process exited or permission denied)",
+                Style::new().fg(Color::Red),
+            );
+            frame.render_widget(Paragraph::new(msg), table_area);
+            return Ok(());
+        }
+        Err(e) => {
+            let msg = Span::styled(format!("error: {}", e), Style::new().fg(Color::Red));
+            frame.render_widget(Paragraph::new(msg), table_area);
+            return Ok(());
+        }
+    };
+
+    let page = table_area.height.saturating_sub(1) as usize;
+    let offset = scroll.sync(files.len(), page);
+    let rows = files.iter().skip(offset).take(page).map(file_row);
+    let header = Row::new(["  FD", "TYPE", "MODE", "       POS", "FLAGS", "NAME"]).bold();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(4),
+            Constraint::Length(10),
+            Constraint::Length(20),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(header);
+    frame.render_widget(table, table_area);
+
+    Ok(())
+}
+
+fn file_row(file: &OpenFile) -> Row<'static> {
+    let name = match &file.kind {
+        OpenFileKind::Socket(Some(sock)) => {
+            let mut name = sock.local.clone().unwrap_or_else(|| file.name.clone());
+            if let Some(remote) = &sock.remote {
+                name.push_str(" → ");
+                name.push_str(remote);
+            }
+            if let Some(state) = &sock.state {
+                name.push_str(&format!(" ({})", state));
+            }
+            name
+        }
+        _ => file.name.clone(),
+    };
+    let style = match file.kind {
+        OpenFileKind::Path => Style::new(),
+        OpenFileKind::Socket(_) => Style::new().fg(Color::Cyan),
+        OpenFileKind::Pipe => Style::new().fg(Color::Yellow),
+        _ => Style::new().fg(Color::DarkGray),
+    };
+    Row::new([
+        Cell::new(format!("{:>4}", file.fd)),
+        Cell::new(file.kind.label()),
+        Cell::new(file.access.map(|a| a.label()).unwrap_or_default()),
+        Cell::new(file.pos.map(|p| format!("{:>10}", p)).unwrap_or_default()),
+        Cell::new(file.flags.join(",")),
+        Cell::new(name),
+    ])
+    .style(style)
+}
@@ -2,13 +2,34 @@
 
 use anyhow::Result;
 use crossterm::event::KeyCode;
-use ratatui::layout::{Alignment, Constraint, Flex, Layout};
+use ratatui::layout::Alignment;
 use ratatui::style::{Color, Style, Stylize};
-use ratatui::widgets::{Block, Clear, List, ListItem, Padding};
+use ratatui::widgets::{Block, ListItem, Padding};
 use ratatui::Frame;
 
 use crate::model::MonitorState;
 
+use super::popup::render_list_popup;
+
+/// Get the label to display for a key.
+fn key_label(code: &KeyCode) -> Option<String> {
+    match code {
+        KeyCode::Char(' ') => Some("Spc".into()),
+        KeyCode::Char(c) => Some(c.to_string()),
+        KeyCode::Esc => Some("Esc".into()),
+        KeyCode::Enter => Some("Ret".into()),
+        KeyCode::Up => Some("↑".into()),
+        KeyCode::Down => Some("↓".into()),
+        KeyCode::Left => Some("←".into()),
+        KeyCode::Right => Some("→".into()),
+        KeyCode::PageUp => Some("PgUp".into()),
+        KeyCode::PageDown => Some("PgDn".into()),
+        KeyCode::Home => Some("Home".into()),
+        KeyCode::End => Some("End".into()),
+        _ => None,
+    }
+}
+
 pub fn render_help<'b>(
     frame: &mut Frame,
     _state: &MonitorState<'b>,
@@ -30,26 +51,16 @@ pub fn render_help<'b>(
                 }
                 lines.push(ListItem::new(desc.to_string()).style(Style::new().bold()));
             }
-            KeyCode::Char(c) if !desc.starts_with("!") => {
-                lines.push(ListItem::new(format!("{}   {}", c, desc)))
+            _ if desc.starts_with("!") => (),
+            kc => {
+                if let Some(key) = key_label(kc) {
+                    lines.push(ListItem::new(format!("{:<4} {}", key, desc)))
+                }
             }
-            _ => (),
         }
     }
 
-    let height: usize = lines.iter().map(|li| li.height()).sum();
-    let width: usize = lines.iter().map(|li| li.width()).max().unwrap_or_default();
-
-    let help = List::new(lines).block(block);
-
-    let v_layout = Layout::vertical([Constraint::Length(height as u16 + 2)])
-        .flex(Flex::Center)
-        .areas::<1>(frame.area());
-    let h_layout = Layout::horizontal([Constraint::Length(width as u16 + 4)])
-        .flex(Flex::Center)
-        .areas::<1>(v_layout[0]);
-    frame.render_widget(Clear, h_layout[0]);
-    frame.render_widget(help, h_layout[0]);
+    render_list_popup(frame, lines, block);
 
     Ok(())
 }
@@ -0,0 +1,135 @@
+//! Memory map view.
+
+use anyhow::Result;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Clear, Padding, Paragraph, Row, Table};
+use ratatui::Frame;
+
+use crate::backend::error::BackendErrorFilter;
+use crate::model::maps::{map_totals, MapCategory, MemoryMapping};
+use crate::model::{MonitorState, RunningProcesses, ScrollPosition};
+
+use super::util::fmt_bytes;
+
+pub fn render_memory_maps(
+    frame: &mut Frame,
+    state: &MonitorState<'_>,
+    pid: u32,
+    scroll: &ScrollPosition,
+) -> Result<()> {
+    let block = Block::bordered()
+        .title(format!("Memory maps of process {}", pid))
+        .title_style(Style::new().fg(Color::Blue).bold())
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Blue))
+        .padding(Padding::horizontal(1));
+    let area = frame.area();
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let maps = match state.process_memory_maps(pid).acceptable_to_opt() {
+        Ok(Some(maps)) => maps,
+        Ok(None) => {
+            let msg = Span::styled(
+                "memory maps unavailable (process exited or permission denied)",
+                Style::new().fg(Color::Red),
+            );
+            frame.render_widget(Paragraph::new(msg), inner);
+            return Ok(());
+        }
+        Err(e) => {
+            let msg = Span::styled(format!("error: {}", e), Style::new().fg(Color::Red));
+            frame.render_widget(Paragraph::new(msg), inner);
+            return Ok(());
+        }
+    };
+
+    let totals = map_totals(&maps);
+    let [summary_area, _, maps_area, hint] = Layout::vertical([
+        Constraint::Length(totals.len() as u16 + 1),
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Fill(1),
+    ];
+    let summary = Table::new(
+        totals.iter().map(|(cat, t)| {
+            Row::new([
+                cat.label().to_string(),
+                format!("{:>5}", t.count),
+                format!("{:>7}", fmt_bytes(t.size)),
+                format!("{:>7}", fmt_bytes(t.rss)),
+                format!("{:>7}", fmt_bytes(t.pss)),
+                format!("{:>7}", fmt_bytes(t.swap)),
+            ])
+            .style(category_style(*cat))
+        }),
+        widths,
+    )
+    .header(
+        Row::new([
+            "CATEGORY", "  MAPS", "   VIRT", "    RSS", "    PSS", "   SWAP",
+        ])
+        .bold(),
+    );
+    frame.render_widget(summary, summary_area);
+
+    let mut largest: Vec<&MemoryMapping> = maps.iter().collect();
+    largest.sort_by(|a, b| b.rss.cmp(&a.rss).then(b.size.cmp(&a.size)));
+    let page = maps_area.height.saturating_sub(1) as usize;
+    let offset = scroll.sync(largest.len(), page);
+    let rows = largest.iter().skip(offset).take(page).map(|m| {
+        Row::new([
+            format!("{:012x}", m.start),
+            m.perms.clone(),
+            format!("{:>7}", fmt_bytes(m.size)),
+            format!("{:>7}", fmt_bytes(m.rss)),
+            format!("{:>7}", fmt_bytes(m.pss)),
+            format!("{:>7}", fmt_bytes(m.swap)),
+            m.path
+                .clone()
+                .unwrap_or_else(|| format!("[{}]", m.category.label())),
+        ])
+        .style(category_style(m.category))
+    });
+    let table = Table::new(rows, widths).header(
+        Row::new([
+            "ADDRESS", "PERM", "   VIRT", "    RSS", "    PSS", "   SWAP", "MAPPING",
+        ])
+        .bold(),
+    );
+    frame.render_widget(table, maps_area);
+
+    frame.render_widget(
+        Paragraph::new("largest mappings first; ↑↓ PgUp PgDn to scroll, esc to close")
+            .style(Style::new().italic()),
+        hint,
+    );
+
+    Ok(())
+}
+
+fn category_style(cat: MapCategory) -> Style {
+    match cat {
+        MapCategory::Heap | MapCategory::Anonymous => Style::new().fg(Color::Green),
+        MapCategory::Stack => Style::new().fg(Color::Yellow),
+        MapCategory::Program | MapCategory::SharedLib => Style::new(),
+        MapCategory::File => Style::new().fg(Color::Cyan),
+        MapCategory::SharedMemory => Style::new().fg(Color::Magenta),
+        MapCategory::Device => Style::new().fg(Color::Red),
+        MapCategory::Other => Style::new().fg(Color::DarkGray),
+    }
+}
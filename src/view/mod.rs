@@ -1,10 +1,28 @@
 //! View code.
 
 mod bin1c;
+mod columns;
 mod dashboard;
+mod detail;
+mod environ;
+mod files;
 mod help;
+mod maps;
+mod popup;
+mod prompt;
+mod signals;
 mod util;
 mod widgets;
 
-pub use dashboard::render_dashboard;
+pub use columns::render_column_chooser;
+pub use dashboard::{
+    optional_process_columns, render_dashboard, render_summary_detail, sort_columns,
+    DashboardLayout, Summary,
+};
+pub use detail::render_process_detail;
+pub use environ::render_environment;
+pub use files::render_open_files;
 pub use help::render_help;
+pub use maps::render_memory_maps;
+pub use prompt::render_prompt;
+pub use signals::render_signal_dialog;
@@ -0,0 +1,27 @@
+//! Popup boxes drawn over other views.
+
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::widgets::{Block, Clear, List, ListItem};
+use ratatui::Frame;
+
+/// Get the area for a popup of a given size (including its border), centred in
+/// an area.
+pub fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Render a list in a popup centred on the screen and sized to fit its items.
+/// The block is expected to have a border and one column of horizontal padding.
+pub fn render_list_popup(frame: &mut Frame, items: Vec<ListItem<'_>>, block: Block<'_>) {
+    let height: usize = items.iter().map(|li| li.height()).sum();
+    let width: usize = items.iter().map(|li| li.width()).max().unwrap_or_default();
+    let area = popup_area(frame.area(), width as u16 + 4, height as u16 + 2);
+    frame.render_widget(Clear, area);
+    frame.render_widget(List::new(items).block(block), area);
+}
@@ -0,0 +1,28 @@
+//! Single-line input prompt.
+
+use anyhow::Result;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+use ratatui::Frame;
+
+/// Render an input prompt on the bottom line of the screen.
+pub fn render_prompt(frame: &mut Frame, label: &str, input: &str) -> Result<()> {
+    let screen = frame.area();
+    let area = Rect {
+        y: screen.bottom().saturating_sub(1),
+        height: 1.min(screen.height),
+        ..screen
+    };
+    let prompt = Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::new().fg(Color::Blue).bold()),
+        Span::from(input),
+    ]);
+    let cursor = area.x + prompt.width() as u16;
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(prompt), area);
+    frame.set_cursor_position(Position::new(cursor.min(area.right()), area.y));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! Signal chooser dialog.
+
+use anyhow::Result;
+use ratatui::layout::Alignment;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{Block, ListItem, Padding};
+use ratatui::Frame;
+
+use crate::model::ProcessSignal;
+
+use super::popup::render_list_popup;
+
+fn signal_desc(signal: ProcessSignal) -> &'static str {
+    match signal {
+        ProcessSignal::Term => "terminate",
+        ProcessSignal::Kill => "kill (cannot be caught)",
+        ProcessSignal::Int => "interrupt",
+        ProcessSignal::Hup => "hang up",
+        ProcessSignal::Stop => "stop (pause)",
+        ProcessSignal::Cont => "continue",
+        ProcessSignal::Usr1 => "user signal 1",
+        ProcessSignal::Usr2 => "user signal 2",
+    }
+}
+
+pub fn render_signal_dialog(frame: &mut Frame, targets: &[u32], cursor: usize) -> Result<()> {
+    let title = match targets {
+        [pid] => format!("Send signal to {}", pid),
+        _ => format!("Send signal to {} processes", targets.len()),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .title_style(Style::new().fg(Color::Red))
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(Color::Red))
+        .padding(Padding::horizontal(1));
+
+    let mut lines = Vec::<ListItem>::new();
+    for (i, sig) in ProcessSignal::ALL.iter().enumerate() {
+        let item = ListItem::new(format!("{:<5} {}", sig.name(), signal_desc(*sig)));
+        lines.push(if i == cursor {
+            item.style(Style::new().reversed())
+        } else {
+            item
+        });
+    }
+    lines.push(ListItem::new(""));
+    lines.push(ListItem::new("enter to send, esc to cancel").style(Style::new().italic()));
+
+    render_list_popup(frame, lines, block);
+
+    Ok(())
+}
@@ -2,11 +2,13 @@ use std::time::Duration;
 
 use friendly::{
     quantity::QVal,
-    scale::{Prefix, PrefixFamily},
+    scale::{Decimal, Prefix, PrefixFamily},
     Quantity,
 };
 use ratatui::style::Color;
 
+use crate::model::ResourceLimit;
+
 use super::bin1c::Bin1C;
 
 pub fn level_color(v: f32) -> Color {
@@ -28,6 +30,16 @@ pub fn fmt_duration(dur: Duration) -> String {
     }
 }
 
+/// Format an age, switching to days and hours for long-running processes.
+pub fn fmt_age(dur: Duration) -> String {
+    let secs = dur.as_secs();
+    if secs >= 86400 {
+        format!("{}d{}h", secs / 86400, secs / 3600 % 24)
+    } else {
+        fmt_duration(dur)
+    }
+}
+
 pub fn fmt_bytes<Q: QVal>(bytes: Q) -> String {
     Quantity::<_, Bin1C>::new(bytes)
         .sig_figs(3)
@@ -40,6 +52,17 @@ pub fn fmt_int_bytes<Q: QVal>(bytes: Q) -> String {
     format!("{:.0}{}", b, p.label())
 }
 
+pub fn fmt_int_si<Q: QVal>(val: Q) -> String {
+    let (v, p) = Decimal::autoscale(val.as_float());
+    format!("{:.0}{}", v, p.label())
+}
+
 pub fn fmt_si_val<Q: QVal>(val: Q) -> String {
     Quantity::decimal(val).sig_figs(3).space(false).to_string()
 }
+
+/// Format a resource limit as `soft/hard`.
+pub fn fmt_limit(limit: &ResourceLimit) -> String {
+    let fmt = |v: Option<u64>| v.map(fmt_int_si).unwrap_or("inf".into());
+    format!("{}/{}", fmt(limit.soft), fmt(limit.hard))
+}
@@ -152,7 +152,7 @@ impl InfoCols {
     pub fn num_cols(&self) -> u16 {
         let mut n = 0;
         n += self.entries.len() as u16 / COL_ROWS;
-        if self.entries.len() as u16 % COL_ROWS > 0 {
+        if !(self.entries.len() as u16).is_multiple_of(COL_ROWS) {
             n += 1;
         }
         n
@@ -12,6 +12,8 @@ use ratatui::{
 pub struct TableGroup<'a> {
     tables: Vec<TGTable<'a>>,
     widths: Vec<u16>,
+    /// Number of lines scrolled off the top.
+    offset: u16,
 }
 
 pub struct TGTable<'a> {
@@ -29,9 +31,9 @@ struct WidthAccum {
     width: usize,
 }
 
-impl Into<u16> for WidthAccum {
-    fn into(self) -> u16 {
-        self.width as u16
+impl From<WidthAccum> for u16 {
+    fn from(acc: WidthAccum) -> u16 {
+        acc.width as u16
     }
 }
 
@@ -46,6 +48,7 @@ impl<'a> TableGroup<'a> {
         TableGroup {
             tables: Vec::new(),
             widths: Vec::new(),
+            offset: 0,
         }
     }
 
@@ -73,6 +76,17 @@ impl<'a> TableGroup<'a> {
         &mut self.tables[len - 1]
     }
 
+    /// Get the total height of the tables, including the gaps between them.
+    pub fn height(&self) -> u16 {
+        let rows: usize = self.tables.iter().map(|t| t.rows.len() + 1).sum();
+        (rows + 2 * self.tables.len().saturating_sub(1)) as u16
+    }
+
+    /// Scroll the tables by a number of lines.
+    pub fn scroll(&mut self, offset: u16) {
+        self.offset = offset;
+    }
+
     /// Get the widths of the columns in this table.
     fn compute_widths(&mut self) {
         self.widths.clear();
@@ -125,7 +139,13 @@ impl<'a> Widget for TableGroup<'a> {
             }
             heights.push(Constraint::Length(table.rows.len() as u16 + 1));
         }
-        let areas = Layout::new(Direction::Vertical, heights).split(area);
+        // render the full group, then copy the visible lines
+        let full = Rect {
+            height: self.height().max(area.height),
+            ..area
+        };
+        let mut group_buf = Buffer::empty(full);
+        let areas = Layout::new(Direction::Vertical, heights).split(full);
 
         for (i, table) in self.tables.into_iter().enumerate() {
             let area = areas[i * 2];
@@ -133,7 +153,15 @@ impl<'a> Widget for TableGroup<'a> {
             let rows: Vec<Row<'_>> = table.rows.into_iter().map(|r| r.to_row()).collect();
             let header = table.header.to_row();
             let table = Table::new(rows, widths).header(header);
-            Widget::render(table, area, buf);
+            Widget::render(table, area, &mut group_buf);
+        }
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = group_buf.cell((x, y + self.offset)) {
+                    buf[(x, y)] = cell.clone();
+                }
+            }
         }
     }
 }
@@ -158,9 +186,7 @@ impl<'a> TGTable<'a> {
 impl<'a> TGEntry<'a> {
     /// Get this as a row.
     fn to_row(&self) -> Row<'a> {
-        let iter = once(&self.label)
-            .chain(self.values.iter())
-            .map(|v| v.clone());
+        let iter = once(&self.label).chain(self.values.iter()).cloned();
         Row::new(iter)
     }
 }